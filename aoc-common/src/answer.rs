@@ -0,0 +1,67 @@
+/// Turns a part's output into the answer as it would be typed into the puzzle page.
+pub trait Answer {
+    /// `None` when the solution found no answer.
+    fn answer(&self) -> Option<String>;
+
+    /// Extra detail worth showing next to the answer, such as the pixels it was read from.
+    fn diagnostics(&self) -> Option<String> {
+        None
+    }
+}
+
+macro_rules! display_answer {
+    ($($t:ty),*) => {
+        $(impl Answer for $t {
+            fn answer(&self) -> Option<String> {
+                Some(self.to_string())
+            }
+        })*
+    };
+}
+
+display_answer!(i32, i64, i128, u32, u64, usize, String, &str);
+
+impl<T: Answer> Answer for Option<T> {
+    fn answer(&self) -> Option<String> {
+        self.as_ref().and_then(T::answer)
+    }
+
+    fn diagnostics(&self) -> Option<String> {
+        self.as_ref().and_then(T::diagnostics)
+    }
+}
+
+/// An answer together with a note on how it was reached.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnosed<T> {
+    pub answer: T,
+    pub diagnostics: Option<String>,
+}
+
+impl<T: Answer> Answer for Diagnosed<T> {
+    fn answer(&self) -> Option<String> {
+        self.answer.answer()
+    }
+
+    fn diagnostics(&self) -> Option<String> {
+        self.diagnostics
+            .clone()
+            .or_else(|| self.answer.diagnostics())
+    }
+}
+
+#[test]
+fn test_answers() {
+    assert_eq!(42.answer(), Some("42".to_owned()));
+    assert_eq!(Some("CMZ".to_owned()).answer(), Some("CMZ".to_owned()));
+    assert_eq!(None::<u64>.answer(), None);
+    let diagnosed = Diagnosed {
+        answer: Some(7usize),
+        diagnostics: Some("found 2 candidates".to_owned()),
+    };
+    assert_eq!(diagnosed.answer(), Some("7".to_owned()));
+    assert_eq!(
+        diagnosed.diagnostics(),
+        Some("found 2 candidates".to_owned())
+    );
+}
@@ -0,0 +1,36 @@
+//! Stepping through a solution and questioning its state at a prompt.
+
+use crate::{vis::Palette, Params, Solution};
+
+/// A solution part way through its work.
+pub trait Exploration {
+    /// Moves on by one step, saying what happened, or returns `None` once there is nothing
+    /// left to do.
+    fn step(&mut self) -> Option<String>;
+    /// Draws the current state, colouring it through `palette`.
+    fn show(&self, palette: &Palette) -> String;
+    /// Answers a question about the current state, given as the words it was typed as.
+    fn query(&self, words: &[&str]) -> Result<String, String>;
+    /// The questions `query` understands, each with what it answers.
+    fn queries(&self) -> &'static [(&'static str, &'static str)];
+}
+
+/// A day whose parsed input can be explored at `aoc repl`.
+pub trait Explore: Solution {
+    fn explore<'a>(input: &'a Self::Input, params: &Params) -> Box<dyn Exploration + 'a>;
+}
+
+/// Reads a position typed as `a,b`.
+pub fn parse_pair(word: &str) -> Result<(usize, usize), String> {
+    word.split_once(',')
+        .and_then(|(a, b)| Some((a.trim().parse().ok()?, b.trim().parse().ok()?)))
+        .ok_or_else(|| format!("expected two numbers like `3,4`, not `{}`", word))
+}
+
+#[test]
+fn test_parse_pair() {
+    assert_eq!(parse_pair("3,4"), Ok((3, 4)));
+    assert_eq!(parse_pair("3, 4"), Ok((3, 4)));
+    assert!(parse_pair("3").is_err());
+    assert!(parse_pair("a,4").is_err());
+}
@@ -0,0 +1,117 @@
+use std::ops::RangeInclusive;
+
+/// Produces random, valid puzzle inputs for stress-testing a day's solution.
+pub trait Generate {
+    /// A size giving inputs roughly as large as the real puzzle input.
+    const DEFAULT_SIZE: usize;
+
+    /// What `size` counts is up to each day (elves, rounds, grid width...), but bigger sizes
+    /// must give bigger inputs.
+    fn generate(rng: &mut Rng, size: usize) -> String;
+}
+
+/// A small seeded SplitMix64 generator, so generated inputs are reproducible everywhere.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A uniformly random number in `0..n`. Panics if `n` is zero.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "cannot pick a number below 0");
+        // Reject the top sliver of values that would bias the result towards small numbers.
+        let zone = u64::MAX - u64::MAX % n;
+        loop {
+            let x = self.next_u64();
+            if x < zone {
+                return x % n;
+            }
+        }
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "empty range {}..={}", start, end);
+        let span = end.abs_diff(start);
+        if span == u64::MAX {
+            return self.next_u64() as i64;
+        }
+        start.wrapping_add(self.below(span + 1) as i64)
+    }
+
+    /// A random index into a collection of length `len`.
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    /// True with probability `numerator / denominator`.
+    pub fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.below(denominator) < numerator
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.index(i + 1);
+            items.swap(i, j);
+        }
+    }
+
+    /// A random lowercase word with a length in `len`.
+    pub fn word(&mut self, len: RangeInclusive<i64>) -> String {
+        let len = self.range(len);
+        (0..len)
+            .map(|_| (b'a' + self.below(26) as u8) as char)
+            .collect()
+    }
+}
+
+#[test]
+fn test_rng_is_reproducible() {
+    let mut a = Rng::new(42);
+    let mut b = Rng::new(42);
+    let mut c = Rng::new(43);
+    let xs: Vec<u64> = (0..8).map(|_| a.next_u64()).collect();
+    assert_eq!(xs, (0..8).map(|_| b.next_u64()).collect::<Vec<_>>());
+    assert_ne!(xs, (0..8).map(|_| c.next_u64()).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_rng_range() {
+    let mut rng = Rng::new(7);
+    let mut seen = [false; 5];
+    for _ in 0..1000 {
+        let x = rng.range(-2..=2);
+        assert!((-2..=2).contains(&x));
+        seen[(x + 2) as usize] = true;
+    }
+    assert_eq!(seen, [true; 5]);
+    assert_eq!(rng.range(3..=3), 3);
+    rng.range(i64::MIN..=i64::MAX);
+}
+
+#[test]
+fn test_shuffle_keeps_items() {
+    let mut rng = Rng::new(1);
+    let mut items: Vec<u32> = (0..20).collect();
+    rng.shuffle(&mut items);
+    assert_ne!(items, (0..20).collect::<Vec<_>>());
+    items.sort();
+    assert_eq!(items, (0..20).collect::<Vec<_>>());
+}
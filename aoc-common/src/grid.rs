@@ -0,0 +1,377 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use crate::{lines, ParseError};
+
+/// A cell position, counted in rows down and columns right from the top-left corner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Pos {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Pos {
+    pub fn new(row: usize, col: usize) -> Self {
+        Self { row, col }
+    }
+
+    /// The position one step in `direction`, or `None` if that would go above or left of the
+    /// origin. Steps past the bottom or right edge are only caught by the grid.
+    pub fn step(self, direction: Direction) -> Option<Pos> {
+        let (d_row, d_col) = direction.offset();
+        Some(Pos {
+            row: self.row.checked_add_signed(d_row)?,
+            col: self.col.checked_add_signed(d_col)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+    UpLeft,
+    UpRight,
+    DownLeft,
+    DownRight,
+}
+
+impl Direction {
+    /// The four directions sharing an edge, in the order right, down, left, up.
+    pub const ORTHOGONAL: [Direction; 4] = [
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+        Direction::Up,
+    ];
+
+    /// All eight directions, clockwise from right.
+    pub const ALL: [Direction; 8] = [
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+        Direction::Up,
+        Direction::UpRight,
+    ];
+
+    /// The `(row, col)` change of one step in this direction.
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (-1, 0),
+            Direction::Down => (1, 0),
+            Direction::Left => (0, -1),
+            Direction::Right => (0, 1),
+            Direction::UpLeft => (-1, -1),
+            Direction::UpRight => (-1, 1),
+            Direction::DownLeft => (1, -1),
+            Direction::DownRight => (1, 1),
+        }
+    }
+}
+
+/// A rectangular grid stored row by row in a single `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Pos) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |col| Pos::new(row, col)))
+            .map(&mut f)
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Builds a grid from nested rows, or `None` if the rows are not all the same width.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let width = rows.first().map(|row| row.len()).unwrap_or(0);
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        Some(Self {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses one row per line, mapping every character with `parse_cell`. Rows must all be
+    /// the same width.
+    pub fn parse(
+        input: &str,
+        mut parse_cell: impl FnMut(char) -> Result<T, String>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for line in lines(input) {
+            let start = cells.len();
+            for (i, c) in line.text.chars().enumerate() {
+                cells.push(parse_cell(c).map_err(|message| line.error(i + 1, message))?);
+            }
+            let row_width = cells.len() - start;
+            match width {
+                Some(width) if width != row_width => {
+                    return Err(
+                        line.error(1, format!("expected {} cells, found {}", width, row_width))
+                    );
+                }
+                _ => width = Some(row_width),
+            }
+            height += 1;
+        }
+        match width {
+            Some(width) if width > 0 => Ok(Self {
+                width,
+                height,
+                cells,
+            }),
+            _ => Err(ParseError::new(1, 1, "empty grid")),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.row < self.height && pos.col < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.index_of(pos).map(|i| &mut self.cells[i])
+    }
+
+    fn index_of(&self, pos: Pos) -> Option<usize> {
+        self.contains(pos).then(|| pos.row * self.width + pos.col)
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| Pos::new(row, col)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(col).step_by(self.width.max(1))
+    }
+
+    /// The in-bounds positions next to `pos`, in `Direction::ORTHOGONAL` order.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.neighbours_in(pos, &Direction::ORTHOGONAL)
+    }
+
+    /// The in-bounds positions next to `pos` including diagonals, in `Direction::ALL` order.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.neighbours_in(pos, &Direction::ALL)
+    }
+
+    fn neighbours_in(
+        &self,
+        pos: Pos,
+        directions: &'static [Direction],
+    ) -> impl Iterator<Item = Pos> + '_ {
+        directions
+            .iter()
+            .filter_map(move |&direction| pos.step(direction))
+            .filter(|&pos| self.contains(pos))
+    }
+
+    /// The positions from `pos` (exclusive) to the edge of the grid in `direction`.
+    pub fn ray(&self, pos: Pos, direction: Direction) -> impl Iterator<Item = Pos> + '_ {
+        std::iter::successors(Some(pos), move |pos| pos.step(direction))
+            .skip(1)
+            .take_while(|&pos| self.contains(pos))
+    }
+
+    pub fn positions_of<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Pos> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos).unwrap_or_else(|| {
+            panic!(
+                "position {:?} is outside a {}x{} grid",
+                pos, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("position {:?} is outside a {}x{} grid", pos, width, height))
+    }
+}
+
+/// Writes each row of cells on its own line, with no trailing newline.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+fn digits(input: &str) -> Result<Grid<u32>, ParseError> {
+    Grid::parse(input, |c| c.to_digit(10).ok_or("not a digit".to_owned()))
+}
+
+#[test]
+fn test_parse() {
+    let grid = digits("12\n34\n").unwrap();
+    assert_eq!(grid, Grid::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap());
+    assert_eq!(grid.height(), 2);
+    assert_eq!(grid.width(), 2);
+    assert_eq!(
+        digits("12\n3x\n"),
+        Err(ParseError::new(2, 2, "not a digit"))
+    );
+    assert_eq!(
+        digits("12\n345\n"),
+        Err(ParseError::new(2, 1, "expected 2 cells, found 3"))
+    );
+    assert_eq!(digits(""), Err(ParseError::new(1, 1, "empty grid")));
+}
+
+#[test]
+fn test_index() {
+    let mut grid = digits("123\n456\n").unwrap();
+    assert_eq!(grid[Pos::new(1, 0)], 4);
+    assert_eq!(grid.get(Pos::new(0, 3)), None);
+    assert_eq!(grid.get(Pos::new(2, 0)), None);
+    grid[Pos::new(0, 2)] = 9;
+    assert_eq!(grid.row(0), &[1, 2, 9]);
+    assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+    assert_eq!(grid.rows().count(), 2);
+}
+
+#[test]
+fn test_neighbours4() {
+    let grid = digits("123\n456\n789\n").unwrap();
+    let values = |pos| grid.neighbours4(pos).map(|p| grid[p]).collect::<Vec<_>>();
+    assert_eq!(
+        grid.neighbours4(Pos::new(0, 0)).collect::<Vec<_>>(),
+        vec![Pos::new(0, 1), Pos::new(1, 0)]
+    );
+    assert_eq!(values(Pos::new(0, 0)), vec![2, 4]);
+    assert_eq!(values(Pos::new(1, 1)), vec![6, 8, 4, 2]);
+    assert_eq!(values(Pos::new(1, 0)), vec![5, 7, 1]);
+    assert_eq!(values(Pos::new(2, 2)), vec![8, 6]);
+}
+
+#[test]
+fn test_neighbours8() {
+    let grid = digits("123\n456\n789\n").unwrap();
+    let values = |pos| grid.neighbours8(pos).map(|p| grid[p]).collect::<Vec<_>>();
+    assert_eq!(values(Pos::new(1, 1)), vec![6, 9, 8, 7, 4, 1, 2, 3]);
+    assert_eq!(values(Pos::new(0, 0)), vec![2, 5, 4]);
+}
+
+#[test]
+fn test_ray() {
+    let grid = digits("123\n456\n789\n").unwrap();
+    let values = |pos, direction| {
+        grid.ray(pos, direction)
+            .map(|p| grid[p])
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(values(Pos::new(1, 1), Direction::Up), vec![2]);
+    assert_eq!(values(Pos::new(0, 0), Direction::DownRight), vec![5, 9]);
+    assert_eq!(values(Pos::new(2, 2), Direction::Left), vec![8, 7]);
+    assert_eq!(values(Pos::new(0, 2), Direction::Right), vec![]);
+}
+
+#[test]
+fn test_positions_of() {
+    let grid = Grid::from_rows(vec![vec!['a', 'b'], vec!['b', 'a']]).unwrap();
+    assert_eq!(
+        grid.positions_of(&'a').collect::<Vec<_>>(),
+        vec![Pos::new(0, 0), Pos::new(1, 1)]
+    );
+    assert_eq!(grid.positions_of(&'c').count(), 0);
+}
+
+#[test]
+fn test_display() {
+    let grid = Grid::from_fn(3, 2, |pos| if pos.row == pos.col { '#' } else { '.' });
+    assert_eq!(grid.to_string(), "#..\n.#.");
+    assert_eq!(grid.map(|&c| c == '#').cells().filter(|&&b| b).count(), 2);
+}
@@ -0,0 +1,171 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    fs::File,
+    io::{self, stdin, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+};
+
+use flate2::read::MultiGzDecoder;
+
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Where to read a day's puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    /// A file, or a directory holding `input.txt`.
+    Path(PathBuf),
+    /// The day's `example.txt`, in `dayN/` or the current directory.
+    Example,
+}
+
+/// Why puzzle input could not be read.
+#[derive(Debug)]
+pub struct InputError {
+    /// What was being read, such as a path or `stdin`.
+    pub name: String,
+    pub error: io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.error.kind() {
+            io::ErrorKind::NotFound => write!(f, "{} does not exist", self.name),
+            _ => write!(f, "reading {}: {}", self.name, self.error),
+        }
+    }
+}
+
+impl Error for InputError {}
+
+impl InputSource {
+    /// Picks the source from `--input <path>`, `--example`, or `-` for stdin, defaulting
+    /// to stdin.
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut source = None;
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let next = match arg.as_str() {
+                "--example" => InputSource::Example,
+                "-" => InputSource::Stdin,
+                "--input" => {
+                    let path = args.next().ok_or("--input needs a path")?;
+                    InputSource::parse(&path)
+                }
+                _ => match arg.strip_prefix("--input=") {
+                    Some(path) => InputSource::parse(path),
+                    None => return Err(format!("unexpected argument `{}`", arg)),
+                },
+            };
+            if source.replace(next).is_some() {
+                return Err("only one input can be given".to_owned());
+            }
+        }
+        Ok(source.unwrap_or(InputSource::Stdin))
+    }
+
+    /// A path argument, where `-` means stdin.
+    pub fn parse(path: &str) -> Self {
+        match path {
+            "-" => InputSource::Stdin,
+            path => InputSource::Path(PathBuf::from(path)),
+        }
+    }
+
+    /// The file this source reads for `day`, or `None` for stdin.
+    pub fn path(&self, day: u8) -> Option<PathBuf> {
+        match self {
+            InputSource::Stdin => None,
+            InputSource::Path(path) if path.is_dir() => Some(path.join("input.txt")),
+            InputSource::Path(path) => Some(path.clone()),
+            InputSource::Example => {
+                let in_workspace = Path::new(&format!("day{}", day)).join("example.txt");
+                let here = PathBuf::from("example.txt");
+                Some(if !in_workspace.exists() && here.exists() {
+                    here
+                } else {
+                    in_workspace
+                })
+            }
+        }
+    }
+
+    /// How errors should refer to this source.
+    pub fn name(&self, day: u8) -> String {
+        match self.path(day) {
+            Some(path) => path.display().to_string(),
+            None => "stdin".to_owned(),
+        }
+    }
+
+    /// Opens the input to be read a piece at a time, decompressing it if it is gzipped.
+    pub fn open(&self, day: u8) -> Result<Box<dyn BufRead>, InputError> {
+        let error = |error| InputError {
+            name: self.name(day),
+            error,
+        };
+        let mut reader: Box<dyn BufRead> = match self.path(day) {
+            Some(path) => Box::new(BufReader::new(File::open(path).map_err(error)?)),
+            None => Box::new(stdin().lock()),
+        };
+        if reader.fill_buf().map_err(error)?.starts_with(&GZIP_MAGIC) {
+            reader = Box::new(BufReader::new(MultiGzDecoder::new(reader)));
+        }
+        Ok(reader)
+    }
+
+    /// Reads the whole input, decompressing it if it is gzipped.
+    pub fn read(&self, day: u8) -> Result<String, InputError> {
+        let error = |error| InputError {
+            name: self.name(day),
+            error,
+        };
+        let mut bytes = Vec::new();
+        self.open(day)?.read_to_end(&mut bytes).map_err(error)?;
+        String::from_utf8(bytes).map_err(|_| {
+            error(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "input is not valid UTF-8",
+            ))
+        })
+    }
+}
+
+#[test]
+fn test_from_args() {
+    let args = |args: &[&str]| InputSource::from_args(args.iter().map(|arg| arg.to_string()));
+    assert_eq!(args(&[]), Ok(InputSource::Stdin));
+    assert_eq!(args(&["--example"]), Ok(InputSource::Example));
+    assert_eq!(
+        args(&["--input", "in.txt"]),
+        Ok(InputSource::Path(PathBuf::from("in.txt")))
+    );
+    assert_eq!(args(&["--input=-"]), Ok(InputSource::Stdin));
+    assert!(args(&["--input"]).is_err());
+    assert!(args(&["--example", "--input", "in.txt"]).is_err());
+    assert!(args(&["input"]).is_err());
+}
+
+#[test]
+fn test_read_gzip_and_directories() {
+    use flate2::{write::GzEncoder, Compression};
+    use std::io::Write;
+
+    let dir = std::env::temp_dir().join(format!("aoc-input-test-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("input.txt"), "plain\n").unwrap();
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(b"zipped\n").unwrap();
+    std::fs::write(dir.join("input.txt.gz"), encoder.finish().unwrap()).unwrap();
+
+    let read = |path: PathBuf| InputSource::Path(path).read(1);
+    assert_eq!(read(dir.clone()).unwrap(), "plain\n");
+    assert_eq!(read(dir.join("input.txt.gz")).unwrap(), "zipped\n");
+    let missing = read(dir.join("missing.txt")).unwrap_err();
+    assert_eq!(
+        missing.to_string(),
+        format!("{} does not exist", dir.join("missing.txt").display())
+    );
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,17 @@
+pub mod answer;
+pub mod explore;
+pub mod generate;
+pub mod grid;
+pub mod input;
+pub mod params;
+pub mod parse;
+pub mod position;
+pub mod solution;
+pub mod vis;
+
+pub use answer::Answer;
+pub use generate::{Generate, Rng};
+pub use input::{InputError, InputSource};
+pub use params::{Param, Params, ParamsFile};
+pub use parse::{lines, ParseError};
+pub use solution::Solution;
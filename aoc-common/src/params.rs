@@ -0,0 +1,231 @@
+use std::{collections::BTreeMap, fmt::Display, fs, io, path::Path};
+
+use serde::Deserialize;
+
+/// A number the puzzle text fixes, like the row day 15 asks about, which an example input
+/// may need changed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    /// The value for the real puzzle input.
+    pub default: u64,
+    /// The largest value the day can use, such as `i32::MAX` for a coordinate.
+    pub max: u64,
+    pub help: &'static str,
+}
+
+/// A value for each of a day's [`Param`]s.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Params {
+    declared: &'static [Param],
+    values: Vec<u64>,
+}
+
+impl Params {
+    pub fn defaults(declared: &'static [Param]) -> Self {
+        Self {
+            declared,
+            values: declared.iter().map(|param| param.default).collect(),
+        }
+    }
+
+    pub fn declared(&self) -> &'static [Param] {
+        self.declared
+    }
+
+    /// Panics if the day never declared `name`.
+    pub fn get(&self, name: &str) -> u64 {
+        match self.index(name) {
+            Some(index) => self.values[index],
+            None => panic!("no parameter named `{}`", name),
+        }
+    }
+
+    /// The value of `name` as the type its [`Param::max`] was chosen to fit.
+    pub fn value<T: TryFrom<u64>>(&self, name: &str) -> T {
+        T::try_from(self.get(name))
+            .unwrap_or_else(|_| panic!("the max of parameter `{}` does not fit its type", name))
+    }
+
+    pub fn set(&mut self, name: &str, value: u64) -> Result<(), String> {
+        let index = self.index(name).ok_or_else(|| match self.declared {
+            [] => format!("unknown parameter `{}`; there are none", name),
+            declared => {
+                let names: Vec<&str> = declared.iter().map(|param| param.name).collect();
+                format!(
+                    "unknown parameter `{}`; expected one of {}",
+                    name,
+                    names.join(", ")
+                )
+            }
+        })?;
+        let max = self.declared[index].max;
+        if value > max {
+            return Err(format!(
+                "{} is too big for parameter `{}`, which is at most {}",
+                value, name, max
+            ));
+        }
+        self.values[index] = value;
+        Ok(())
+    }
+
+    /// Applies an override written as `name=value`.
+    pub fn assign(&mut self, assignment: &str) -> Result<(), String> {
+        let (name, value) = assignment
+            .split_once('=')
+            .ok_or_else(|| format!("expected `name=value`, got `{}`", assignment))?;
+        let value = value
+            .trim()
+            .parse()
+            .map_err(|_| format!("`{}` is not a valid value for {}", value, name))?;
+        self.set(name.trim(), value)
+    }
+
+    fn index(&self, name: &str) -> Option<usize> {
+        self.declared.iter().position(|param| param.name == name)
+    }
+}
+
+/// Puzzle parameters from `aoc.toml`: a `[dayN]` table per day, and a `[dayN.example]`
+/// table whose values win when the day runs on its example.
+#[derive(Debug, Default, Deserialize)]
+#[serde(transparent)]
+pub struct ParamsFile {
+    days: BTreeMap<String, DayTable>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct DayTable {
+    #[serde(default)]
+    example: BTreeMap<String, u64>,
+    #[serde(flatten)]
+    values: BTreeMap<String, u64>,
+}
+
+impl ParamsFile {
+    /// Returns an empty file if there is nothing at `path`.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(ParamsFile::default()),
+            Err(e) => return Err(format!("reading {}: {}", path.display(), e)),
+        };
+        let file: ParamsFile =
+            toml::from_str(&text).map_err(|e| format!("parsing {}: {}", path.display(), e))?;
+        for name in file.days.keys() {
+            let day = name
+                .strip_prefix("day")
+                .and_then(|day| day.parse::<u8>().ok());
+            if day.is_none() {
+                return Err(format!(
+                    "{}: expected tables like [day15], found [{}]",
+                    path.display(),
+                    name
+                ));
+            }
+        }
+        Ok(file)
+    }
+
+    /// Loads `aoc.toml` from the workspace root, whether a day runs from there or from its
+    /// own directory.
+    pub fn find() -> Result<Self, String> {
+        let here = Path::new("aoc.toml");
+        let above = Path::new("../aoc.toml");
+        Self::load(if !here.exists() && above.exists() {
+            above
+        } else {
+            here
+        })
+    }
+
+    /// Sets the values `day` has in the file, and its example values too if `example`.
+    pub fn apply(&self, day: u8, example: bool, params: &mut Params) -> Result<(), String> {
+        let table_name = format!("day{}", day);
+        let Some(table) = self.days.get(&table_name) else {
+            return Ok(());
+        };
+        let example_values = table.example.iter().filter(|_| example);
+        for (name, &value) in table.values.iter().chain(example_values) {
+            params
+                .set(name, value)
+                .map_err(|e| format!("aoc.toml: [{}]: {}", table_name, e))?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for Params {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, (param, value)) in self.declared.iter().zip(&self.values).enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{}={}", param.name, value)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+const TEST_PARAMS: &[Param] = &[
+    Param {
+        name: "row",
+        default: 2000000,
+        max: i32::MAX as u64,
+        help: "",
+    },
+    Param {
+        name: "max",
+        default: 4000000,
+        max: i32::MAX as u64,
+        help: "",
+    },
+];
+
+#[test]
+fn test_assign() {
+    let mut params = Params::defaults(TEST_PARAMS);
+    assert_eq!(params.get("row"), 2000000);
+    params.assign("row=10").unwrap();
+    params.assign(" max = 20").unwrap();
+    assert_eq!(params.to_string(), "row=10 max=20");
+    assert_eq!(params.value::<i32>("max"), 20);
+    assert_eq!(
+        params.assign("rows=1"),
+        Err("unknown parameter `rows`; expected one of row, max".to_owned())
+    );
+    assert!(params.assign("row").is_err());
+    assert!(params.assign("row=-1").is_err());
+    assert_eq!(
+        params.assign("max=5000000000"),
+        Err("5000000000 is too big for parameter `max`, which is at most 2147483647".to_owned())
+    );
+    assert!(Params::defaults(&[]).assign("row=1").is_err());
+}
+
+#[test]
+fn test_apply() {
+    let file: ParamsFile = toml::from_str(
+        "[day15]\nrow = 12\n\n[day15.example]\nrow = 10\nmax = 20\n\n[day7]\nspace = 1\n",
+    )
+    .unwrap();
+    let apply = |day, example| {
+        let mut params = Params::defaults(TEST_PARAMS);
+        file.apply(day, example, &mut params)
+            .map(|_| params.to_string())
+    };
+    assert_eq!(apply(15, false).unwrap(), "row=12 max=4000000");
+    assert_eq!(apply(15, true).unwrap(), "row=10 max=20");
+    assert_eq!(apply(1, true).unwrap(), "row=2000000 max=4000000");
+    assert!(apply(7, false)
+        .unwrap_err()
+        .starts_with("aoc.toml: [day7]: unknown parameter `space`"));
+}
+
+#[test]
+fn test_workspace_file() {
+    let file = ParamsFile::load(Path::new("../aoc.toml")).unwrap();
+    assert!(file.days.contains_key("day15"));
+}
@@ -0,0 +1,127 @@
+use std::{error::Error, fmt::Display, str::FromStr};
+
+/// A problem found while parsing puzzle input. Lines and columns are 1-based.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// Renders the error along with the offending line of `input` and a caret under the column.
+    pub fn diagnostic(&self, input: &str) -> String {
+        let mut out = self.to_string();
+        if let Some(text) = input.lines().nth(self.line.saturating_sub(1)) {
+            let gutter = self.line.to_string().len();
+            out.push_str(&format!("\n{:gutter$} |\n", ""));
+            out.push_str(&format!("{} | {}\n", self.line, text));
+            out.push_str(&format!(
+                "{:gutter$} | {:>column$}",
+                "",
+                "^",
+                column = self.column.max(1)
+            ));
+        }
+        out
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// One line of puzzle input, remembering where it came from so errors can point at it.
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub fn error(&self, column: usize, message: impl Into<String>) -> ParseError {
+        ParseError::new(self.number, column, message)
+    }
+
+    /// An error pointing at `part`, which must be a slice of this line's text.
+    pub fn error_at(&self, part: &str, message: impl Into<String>) -> ParseError {
+        self.error(self.column_of(part), message)
+    }
+
+    pub fn column_of(&self, part: &str) -> usize {
+        let offset = (part.as_ptr() as usize).saturating_sub(self.text.as_ptr() as usize);
+        self.text
+            .get(..offset.min(self.text.len()))
+            .map(|prefix| prefix.chars().count())
+            .unwrap_or(0)
+            + 1
+    }
+
+    pub fn parse_at<T: FromStr>(&self, part: &'a str) -> Result<T, ParseError> {
+        part.parse()
+            .map_err(|_| self.error_at(part, format!("invalid number `{}`", part)))
+    }
+
+    pub fn split_once(&self, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+        self.text.split_once(delimiter).ok_or_else(|| {
+            self.error(
+                self.text.chars().count() + 1,
+                format!("expected `{}`", delimiter),
+            )
+        })
+    }
+
+    pub fn strip_prefix(&self, prefix: &str) -> Result<&'a str, ParseError> {
+        self.text
+            .strip_prefix(prefix)
+            .ok_or_else(|| self.error(1, format!("expected `{}`", prefix)))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+}
+
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(i, text)| Line {
+        number: i + 1,
+        text,
+    })
+}
+
+#[test]
+fn test_parse_error_diagnostic() {
+    let input = "1-2,3-4\n5-x,7-8\n";
+    let line = lines(input).nth(1).unwrap();
+    let error = line.parse_at::<i32>(&line.text[2..3]).unwrap_err();
+    assert_eq!(error, ParseError::new(2, 3, "invalid number `x`"));
+    assert_eq!(
+        error.diagnostic(input),
+        "line 2, column 3: invalid number `x`\n  |\n2 | 5-x,7-8\n  |   ^"
+    );
+}
+
+#[test]
+fn test_split_once() {
+    let line = lines("abc").next().unwrap();
+    assert_eq!(
+        line.split_once(","),
+        Err(ParseError::new(1, 4, "expected `,`"))
+    );
+}
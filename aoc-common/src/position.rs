@@ -0,0 +1,35 @@
+/// A point on an unbounded plane.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point {
+    pub fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    pub fn manhattan_distance(&self, other: &Point) -> i32 {
+        (self.x.abs_diff(other.x) + self.y.abs_diff(other.y)) as i32
+    }
+
+    /// Whether the two points overlap or are adjacent, including diagonally.
+    pub fn is_touching(&self, other: &Point) -> bool {
+        (self.x - other.x).abs() <= 1 && (self.y - other.y).abs() <= 1
+    }
+}
+
+#[test]
+fn test_manhattan_distance() {
+    assert_eq!(Point::new(0, 0).manhattan_distance(&Point::new(3, -4)), 7);
+    assert_eq!(Point::new(-2, 5).manhattan_distance(&Point::new(-2, 5)), 0);
+}
+
+#[test]
+fn test_is_touching() {
+    assert!(Point::new(0, 0).is_touching(&Point::new(0, 0)));
+    assert!(Point::new(0, 0).is_touching(&Point::new(1, 1)));
+    assert!(Point::new(0, 0).is_touching(&Point::new(-1, 0)));
+    assert!(!Point::new(0, 0).is_touching(&Point::new(2, 1)));
+}
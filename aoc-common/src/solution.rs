@@ -0,0 +1,134 @@
+use std::{env::args, process::exit};
+
+use crate::{
+    answer::Answer,
+    input::InputSource,
+    params::{Param, Params, ParamsFile},
+    parse::ParseError,
+};
+
+/// A day's puzzle: how to parse its input and how to answer both parts.
+pub trait Solution {
+    const DAY: u8;
+    type Input;
+    type Output1: Answer;
+    type Output2: Answer;
+    /// What the parts can be tuned by, which `params` always has a value for.
+    const PARAMS: &'static [Param] = &[];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part_1(input: &Self::Input, params: &Params) -> Self::Output1;
+    fn part_2(input: &Self::Input, params: &Params) -> Self::Output2;
+}
+
+/// Reads and parses the input, exiting with a diagnostic if it is unreadable or malformed.
+pub fn read_and_parse<S: Solution>(source: &InputSource) -> S::Input {
+    let input = match source.read(S::DAY) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            exit(1);
+        }
+    };
+    match S::parse(&input) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("error: {}: {}", source.name(S::DAY), e.diagnostic(&input));
+            exit(1);
+        }
+    }
+}
+
+pub fn parse_stdin<S: Solution>() -> S::Input {
+    read_and_parse::<S>(&InputSource::Stdin)
+}
+
+/// The `main` of every day's binary, reading the input named on the command line.
+pub fn run<S: Solution>() {
+    let (source, params) = parse_args::<S>(args().skip(1)).unwrap_or_else(|e| {
+        eprintln!("error: {}", e);
+        eprintln!(
+            "usage: day{} [--input <path> | --example | -] [--param <name=value>]...",
+            S::DAY
+        );
+        exit(2);
+    });
+    let input = read_and_parse::<S>(&source);
+    print_solution(1, &S::part_1(&input, &params));
+    print_solution(2, &S::part_2(&input, &params));
+}
+
+/// Takes out `--param name=value` overrides, leaving the rest to [`InputSource::from_args`].
+/// The overrides win over `aoc.toml`, whose example values apply when running the example.
+pub fn parse_args<S: Solution>(
+    args: impl IntoIterator<Item = String>,
+) -> Result<(InputSource, Params), String> {
+    let mut assignments = Vec::new();
+    let mut rest = Vec::new();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if arg == "--param" {
+            assignments.push(args.next().ok_or("--param needs `name=value`")?);
+        } else if let Some(assignment) = arg.strip_prefix("--param=") {
+            assignments.push(assignment.to_owned());
+        } else {
+            rest.push(arg);
+        }
+    }
+    let source = InputSource::from_args(rest)?;
+    let mut params = Params::defaults(S::PARAMS);
+    ParamsFile::find()?.apply(S::DAY, source == InputSource::Example, &mut params)?;
+    for assignment in &assignments {
+        params.assign(assignment)?;
+    }
+    Ok((source, params))
+}
+
+pub fn print_solution(part: u8, output: &impl Answer) {
+    match output.answer() {
+        Some(answer) if answer.contains('\n') => println!("solution {}:\n{}", part, answer),
+        Some(answer) => println!("solution {}: {}", part, answer),
+        None => println!("solution {}: no answer", part),
+    }
+    if let Some(diagnostics) = output.diagnostics() {
+        println!("{}", diagnostics);
+    }
+}
+
+#[test]
+fn test_parse_args() {
+    struct Tuned;
+    impl Solution for Tuned {
+        const DAY: u8 = 0;
+        type Input = ();
+        type Output1 = u64;
+        type Output2 = u64;
+        const PARAMS: &'static [Param] = &[Param {
+            name: "rounds",
+            default: 20,
+            max: u64::MAX,
+            help: "",
+        }];
+
+        fn parse(_: &str) -> Result<Self::Input, ParseError> {
+            Ok(())
+        }
+
+        fn part_1(_: &Self::Input, params: &Params) -> Self::Output1 {
+            params.get("rounds")
+        }
+
+        fn part_2(_: &Self::Input, _: &Params) -> Self::Output2 {
+            0
+        }
+    }
+
+    let args = |args: &[&str]| parse_args::<Tuned>(args.iter().map(|arg| arg.to_string()));
+    let (source, params) = args(&["--param", "rounds=3", "--example"]).unwrap();
+    assert_eq!(source, InputSource::Example);
+    assert_eq!(Tuned::part_1(&(), &params), 3);
+    let (source, params) = args(&["--param=rounds=4"]).unwrap();
+    assert_eq!((source, params.get("rounds")), (InputSource::Stdin, 4));
+    assert!(args(&["--param"]).is_err());
+    assert!(args(&["--param", "round=1"]).is_err());
+}
@@ -0,0 +1,365 @@
+//! Terminal animations of solutions at work.
+
+use std::{
+    fs::File,
+    io::{self, stdin, stdout, BufRead, BufWriter, Write},
+    path::Path,
+    sync::mpsc::{channel, Receiver},
+    thread,
+    time::{Duration, Instant},
+};
+
+use colored::{Color, Colorize};
+
+use crate::{Params, Solution};
+
+/// One picture in an animation.
+pub trait Frame {
+    /// Draws the frame as lines of text, colouring cells through `palette`.
+    fn render(&self, palette: &Palette) -> String;
+}
+
+/// A day that can show its solution being worked out.
+pub trait Visualize: Solution {
+    type Frame: Frame;
+
+    fn frames<'a>(
+        input: &'a Self::Input,
+        params: &Params,
+    ) -> Box<dyn Iterator<Item = Self::Frame> + 'a>;
+}
+
+/// What a cell means, which a palette turns into a colour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Role {
+    Empty,
+    Wall,
+    Trail,
+    Active,
+    Highlight,
+}
+
+impl Role {
+    pub fn name(self) -> &'static str {
+        match self {
+            Role::Empty => "empty",
+            Role::Wall => "wall",
+            Role::Trail => "trail",
+            Role::Active => "active",
+            Role::Highlight => "highlight",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    empty: Option<Color>,
+    wall: Option<Color>,
+    trail: Option<Color>,
+    active: Option<Color>,
+    highlight: Option<Color>,
+    html: bool,
+}
+
+impl Palette {
+    pub const NAMES: [&'static str; 3] = ["default", "mono", "contrast"];
+
+    /// Leaves text uncoloured, for recordings and terminals without colour.
+    pub fn mono() -> Self {
+        Self {
+            empty: None,
+            wall: None,
+            trail: None,
+            active: None,
+            highlight: None,
+            html: false,
+        }
+    }
+
+    /// Wraps each cell in a `<span>` whose class is its role's name, for a stylesheet to colour.
+    pub fn html() -> Self {
+        Self {
+            html: true,
+            ..Self::mono()
+        }
+    }
+
+    pub fn contrast() -> Self {
+        Self {
+            empty: Some(Color::BrightBlack),
+            wall: Some(Color::BrightWhite),
+            trail: Some(Color::BrightCyan),
+            active: Some(Color::BrightYellow),
+            highlight: Some(Color::BrightMagenta),
+            html: false,
+        }
+    }
+
+    pub fn named(name: &str) -> Option<Self> {
+        match name {
+            "default" => Some(Self::default()),
+            "mono" => Some(Self::mono()),
+            "contrast" => Some(Self::contrast()),
+            _ => None,
+        }
+    }
+
+    pub fn paint(&self, text: &str, role: Role) -> String {
+        if self.html {
+            return format!(
+                "<span class=\"{}\">{}</span>",
+                role.name(),
+                escape_html(text)
+            );
+        }
+        let color = match role {
+            Role::Empty => self.empty,
+            Role::Wall => self.wall,
+            Role::Trail => self.trail,
+            Role::Active => self.active,
+            Role::Highlight => self.highlight,
+        };
+        match color {
+            Some(color) => text.color(color).to_string(),
+            None => text.to_owned(),
+        }
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self {
+            empty: None,
+            wall: Some(Color::White),
+            trail: Some(Color::Green),
+            active: Some(Color::Red),
+            highlight: Some(Color::Yellow),
+            html: false,
+        }
+    }
+}
+
+/// Escapes `text` for use in HTML, inside elements or quoted attributes.
+pub fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// A command typed on stdin while an animation plays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Control {
+    TogglePause,
+    Step,
+    Faster,
+    Slower,
+    Quit,
+}
+
+impl Control {
+    pub fn parse(line: &str) -> Option<Self> {
+        match line.trim() {
+            "p" => Some(Control::TogglePause),
+            "" | "s" => Some(Control::Step),
+            "+" => Some(Control::Faster),
+            "-" => Some(Control::Slower),
+            "q" => Some(Control::Quit),
+            _ => None,
+        }
+    }
+}
+
+const HELP: &str = "p: pause/resume  enter: step  +/-: speed  q: quit";
+
+/// Shows frames at a steady rate, optionally recording them to a file.
+pub struct Player {
+    fps: f64,
+    palette: Palette,
+    paused: bool,
+    out: Option<Box<dyn Write>>,
+    recorder: Option<BufWriter<File>>,
+    controls: Option<Receiver<Control>>,
+}
+
+impl Player {
+    pub fn new(fps: f64) -> Self {
+        Self {
+            fps,
+            palette: Palette::default(),
+            paused: false,
+            out: Some(Box::new(stdout())),
+            recorder: None,
+            controls: None,
+        }
+    }
+
+    pub fn palette(mut self, palette: Palette) -> Self {
+        self.palette = palette;
+        self
+    }
+
+    /// Starts on the first frame, waiting for a step or resume command.
+    pub fn paused(mut self, paused: bool) -> Self {
+        self.paused = paused;
+        self
+    }
+
+    /// Draws frames to `out` instead of stdout.
+    pub fn output(mut self, out: impl Write + 'static) -> Self {
+        self.out = Some(Box::new(out));
+        self
+    }
+
+    /// Draws nothing, which only makes sense while recording.
+    pub fn headless(mut self) -> Self {
+        self.out = None;
+        self
+    }
+
+    /// Writes every frame shown to `path`, uncoloured.
+    pub fn record_to(mut self, path: &Path) -> io::Result<Self> {
+        self.recorder = Some(BufWriter::new(File::create(path)?));
+        Ok(self)
+    }
+
+    /// Reads `Control` commands from stdin, one per line.
+    pub fn with_controls(mut self) -> Self {
+        let (sender, receiver) = channel();
+        thread::spawn(move || {
+            for line in stdin().lock().lines() {
+                let Ok(line) = line else { break };
+                if let Some(control) = Control::parse(&line) {
+                    if sender.send(control).is_err() {
+                        break;
+                    }
+                }
+            }
+        });
+        self.controls = Some(receiver);
+        self
+    }
+
+    /// Plays `frames` until they run out or the viewer quits, returning how many were shown.
+    pub fn play<F: Frame>(&mut self, frames: impl Iterator<Item = F>) -> io::Result<usize> {
+        let mut shown = 0;
+        let mut next_frame_at = Instant::now();
+        for frame in frames {
+            shown += 1;
+            self.show(&frame, shown)?;
+            // Apply commands typed since the last frame, waiting for one while paused.
+            loop {
+                match self.next_control(self.paused) {
+                    Some(Control::Step) => {
+                        self.paused = true;
+                        break;
+                    }
+                    Some(Control::TogglePause) => self.paused = !self.paused,
+                    Some(Control::Faster) => self.fps *= 2.0,
+                    Some(Control::Slower) => self.fps /= 2.0,
+                    Some(Control::Quit) => return self.finish(shown),
+                    None => break,
+                }
+            }
+            if self.out.is_some() && !self.paused {
+                next_frame_at += Duration::from_secs_f64(1.0 / self.fps);
+                let now = Instant::now();
+                if next_frame_at > now {
+                    thread::sleep(next_frame_at - now);
+                } else {
+                    next_frame_at = now;
+                }
+            }
+        }
+        self.finish(shown)
+    }
+
+    fn show(&mut self, frame: &impl Frame, number: usize) -> io::Result<()> {
+        if let Some(recorder) = self.recorder.as_mut() {
+            writeln!(recorder, "frame {}", number)?;
+            writeln!(recorder, "{}", frame.render(&Palette::mono()))?;
+        }
+        if let Some(out) = self.out.as_mut() {
+            let status = if self.paused { "paused" } else { "playing" };
+            // Clear the screen and move the cursor home before drawing.
+            write!(out, "\x1b[2J\x1b[H")?;
+            writeln!(out, "{}", frame.render(&self.palette))?;
+            writeln!(out, "frame {}  {:.0} fps  {}", number, self.fps, status)?;
+            if self.controls.is_some() {
+                writeln!(out, "{}", HELP)?;
+            }
+            out.flush()?;
+        }
+        Ok(())
+    }
+
+    fn next_control(&mut self, block: bool) -> Option<Control> {
+        let controls = self.controls.as_ref()?;
+        if block {
+            controls.recv().ok()
+        } else {
+            controls.try_recv().ok()
+        }
+    }
+
+    fn finish(&mut self, shown: usize) -> io::Result<usize> {
+        if let Some(recorder) = self.recorder.as_mut() {
+            recorder.flush()?;
+        }
+        Ok(shown)
+    }
+}
+
+#[cfg(test)]
+struct Counter(usize);
+
+#[cfg(test)]
+impl Frame for Counter {
+    fn render(&self, palette: &Palette) -> String {
+        palette.paint(&"#".repeat(self.0), Role::Wall)
+    }
+}
+
+#[test]
+fn test_record_frames() {
+    let path = std::env::temp_dir().join(format!("aoc-vis-test-{}.txt", std::process::id()));
+    let mut player = Player::new(1000.0)
+        .palette(Palette::contrast())
+        .headless()
+        .record_to(&path)
+        .unwrap();
+    let shown = player.play((1..=3).map(Counter)).unwrap();
+    assert_eq!(shown, 3);
+    let recording = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(recording, "frame 1\n#\nframe 2\n##\nframe 3\n###\n");
+}
+
+#[test]
+fn test_palettes() {
+    assert_eq!(Palette::mono().paint("#", Role::Active), "#");
+    for name in Palette::NAMES {
+        assert!(Palette::named(name).is_some());
+    }
+    assert_eq!(Palette::named("sepia"), None);
+    assert_eq!(
+        Palette::html().paint("<o>", Role::Active),
+        "<span class=\"active\">&lt;o&gt;</span>"
+    );
+}
+
+#[test]
+fn test_parse_control() {
+    assert_eq!(Control::parse("p"), Some(Control::TogglePause));
+    assert_eq!(Control::parse(""), Some(Control::Step));
+    assert_eq!(Control::parse(" q "), Some(Control::Quit));
+    assert_eq!(Control::parse("x"), None);
+}
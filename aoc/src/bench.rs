@@ -0,0 +1,173 @@
+use std::{collections::BTreeMap, fs, io, path::Path, time::Duration};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::days::Samples;
+
+/// Summary of a set of timings, stored in nanoseconds so baselines stay readable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min: u64,
+    pub median: u64,
+    pub max: u64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let mut nanos: Vec<u64> = samples
+            .iter()
+            .map(|d| d.as_nanos().try_into().unwrap_or(u64::MAX))
+            .collect();
+        nanos.sort_unstable();
+        let len = nanos.len();
+        if len == 0 {
+            return None;
+        }
+        let median = if len % 2 == 1 {
+            nanos[len / 2]
+        } else {
+            (nanos[len / 2 - 1] + nanos[len / 2]) / 2
+        };
+        Some(Stats {
+            min: nanos[0],
+            median,
+            max: nanos[len - 1],
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayStats {
+    pub parse: Stats,
+    pub part_1: Stats,
+    pub part_2: Stats,
+}
+
+impl DayStats {
+    pub fn from_samples(samples: &Samples) -> Option<Self> {
+        Some(DayStats {
+            parse: Stats::from_samples(&samples.parse)?,
+            part_1: Stats::from_samples(&samples.part_1)?,
+            part_2: Stats::from_samples(&samples.part_2)?,
+        })
+    }
+
+    fn phases(&self) -> [(&'static str, Stats); 3] {
+        [
+            ("parse", self.parse),
+            ("part 1", self.part_1),
+            ("part 2", self.part_2),
+        ]
+    }
+}
+
+/// Saved benchmark results, keyed by day number.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Baseline {
+    pub days: BTreeMap<u8, DayStats>,
+}
+
+impl Baseline {
+    pub fn load(path: &Path) -> Result<Self> {
+        let text =
+            fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+        serde_json::from_str(&text).with_context(|| format!("parsing {}", path.display()))
+    }
+
+    /// Like `load`, but starts from an empty baseline if the file does not exist yet.
+    pub fn load_or_default(path: &Path) -> Result<Self> {
+        match fs::metadata(path) {
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Baseline::default()),
+            _ => Baseline::load(path),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent).with_context(|| format!("creating {}", parent.display()))?;
+        }
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json + "\n").with_context(|| format!("writing {}", path.display()))
+    }
+}
+
+pub fn print_stats(day: u8, stats: &DayStats, baseline: Option<&DayStats>) {
+    println!("day {}", day);
+    for (i, (phase, current)) in stats.phases().into_iter().enumerate() {
+        let mut line = format!(
+            "  {:<6}  min {:>10}  median {:>10}  max {:>10}",
+            phase,
+            format_nanos(current.min),
+            format_nanos(current.median),
+            format_nanos(current.max)
+        );
+        if let Some(baseline) = baseline {
+            let (_, before) = baseline.phases()[i];
+            line.push_str(&format!(
+                "  {} vs baseline",
+                format_change(before.median, current.median)
+            ));
+        }
+        println!("{}", line);
+    }
+}
+
+fn format_nanos(nanos: u64) -> String {
+    format!("{:.2?}", Duration::from_nanos(nanos))
+}
+
+/// Relative change of the median, negative when the current run is faster.
+fn format_change(before: u64, after: u64) -> String {
+    if before == 0 {
+        return "n/a".to_owned();
+    }
+    let change = (after as f64 - before as f64) / before as f64 * 100.0;
+    format!("{:+.1}%", change)
+}
+
+#[test]
+fn test_stats_from_samples() {
+    let samples: Vec<Duration> = [5, 1, 4, 2].into_iter().map(Duration::from_nanos).collect();
+    assert_eq!(
+        Stats::from_samples(&samples),
+        Some(Stats {
+            min: 1,
+            median: 3,
+            max: 5
+        })
+    );
+    assert_eq!(
+        Stats::from_samples(&samples[..3]).map(|s| s.median),
+        Some(4)
+    );
+    assert_eq!(Stats::from_samples(&[]), None);
+}
+
+#[test]
+fn test_baseline_round_trip() {
+    let stats = Stats {
+        min: 1,
+        median: 2,
+        max: 3,
+    };
+    let mut baseline = Baseline::default();
+    baseline.days.insert(
+        7,
+        DayStats {
+            parse: stats,
+            part_1: stats,
+            part_2: stats,
+        },
+    );
+    let json = serde_json::to_string(&baseline).unwrap();
+    let parsed: Baseline = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.days, baseline.days);
+}
+
+#[test]
+fn test_format_change() {
+    assert_eq!(format_change(200, 150), "-25.0%");
+    assert_eq!(format_change(100, 110), "+10.0%");
+    assert_eq!(format_change(0, 10), "n/a");
+}
@@ -0,0 +1,386 @@
+use std::{
+    hint::black_box,
+    io,
+    marker::PhantomData,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use aoc_common::{
+    explore::Explore,
+    vis::{Player, Visualize},
+    Answer, Generate, Params, ParseError, Rng, Solution,
+};
+
+use crate::repl::Repl;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct PartResult {
+    pub part: Part,
+    /// `None` when the solution found no answer.
+    pub answer: Option<String>,
+    pub diagnostics: Option<String>,
+    pub elapsed: Duration,
+}
+
+#[derive(Debug)]
+pub struct DayResult {
+    pub day: u8,
+    pub parse_elapsed: Duration,
+    pub parts: Vec<PartResult>,
+}
+
+/// Timings of every repetition of a benchmark, in the order they ran.
+#[derive(Debug, Default)]
+pub struct Samples {
+    pub parse: Vec<Duration>,
+    pub part_1: Vec<Duration>,
+    pub part_2: Vec<Duration>,
+}
+
+pub trait Day: Send {
+    fn number(&self) -> u8;
+    /// The day's parameters, at their values for the real input.
+    fn params(&self) -> Params;
+    fn run(&self, input: &str, parts: &[Part], params: &Params) -> Result<DayResult, ParseError>;
+    /// Times parsing and each part separately, `runs` times each.
+    fn bench(&self, input: &str, runs: usize, params: &Params) -> Result<Samples, ParseError>;
+    /// A random puzzle input, `size` defaulting to something like the real input's size.
+    fn generate(&self, seed: u64, size: Option<usize>) -> String;
+}
+
+fn describe(output: &impl Answer) -> (Option<String>, Option<String>) {
+    (output.answer(), output.diagnostics())
+}
+
+fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let value = black_box(f());
+    (value, start.elapsed())
+}
+
+struct Solver<S>(PhantomData<S>);
+
+impl<S: Solution + Generate + Send> Day for Solver<S> {
+    fn number(&self) -> u8 {
+        S::DAY
+    }
+
+    fn params(&self) -> Params {
+        Params::defaults(S::PARAMS)
+    }
+
+    fn run(&self, input: &str, parts: &[Part], params: &Params) -> Result<DayResult, ParseError> {
+        let start = Instant::now();
+        let parsed = S::parse(input)?;
+        let parse_elapsed = start.elapsed();
+        let parts = parts
+            .iter()
+            .map(|&part| {
+                let start = Instant::now();
+                let (answer, diagnostics) = match part {
+                    Part::One => describe(&S::part_1(&parsed, params)),
+                    Part::Two => describe(&S::part_2(&parsed, params)),
+                };
+                PartResult {
+                    part,
+                    answer,
+                    diagnostics,
+                    elapsed: start.elapsed(),
+                }
+            })
+            .collect();
+        Ok(DayResult {
+            day: S::DAY,
+            parse_elapsed,
+            parts,
+        })
+    }
+
+    fn bench(&self, input: &str, runs: usize, params: &Params) -> Result<Samples, ParseError> {
+        let mut samples = Samples::default();
+        let mut parsed = None;
+        for _ in 0..runs {
+            let (result, elapsed) = time(|| S::parse(black_box(input)));
+            parsed = Some(result?);
+            samples.parse.push(elapsed);
+        }
+        let Some(parsed) = parsed else {
+            return Ok(samples);
+        };
+        for _ in 0..runs {
+            samples
+                .part_1
+                .push(time(|| S::part_1(black_box(&parsed), params)).1);
+        }
+        for _ in 0..runs {
+            samples
+                .part_2
+                .push(time(|| S::part_2(black_box(&parsed), params)).1);
+        }
+        Ok(samples)
+    }
+
+    fn generate(&self, seed: u64, size: Option<usize>) -> String {
+        S::generate(&mut Rng::new(seed), size.unwrap_or(S::DEFAULT_SIZE))
+    }
+}
+
+fn solver<S: Solution + Generate + Send + 'static>() -> Box<dyn Day> {
+    Box::new(Solver::<S>(PhantomData))
+}
+
+pub fn all() -> Vec<Box<dyn Day>> {
+    vec![
+        solver::<day1::Day1>(),
+        solver::<day2::Day2>(),
+        solver::<day3::Day3>(),
+        solver::<day4::Day4>(),
+        solver::<day5::Day5>(),
+        solver::<day6::Day6>(),
+        solver::<day7::Day7>(),
+        solver::<day8::Day8>(),
+        solver::<day9::Day9>(),
+        solver::<day10::Day10>(),
+        solver::<day11::Day11>(),
+        solver::<day12::Day12>(),
+        solver::<day13::Day13>(),
+        solver::<day14::Day14>(),
+        solver::<day15::Day15>(),
+    ]
+}
+
+pub fn find(number: u8) -> Option<Box<dyn Day>> {
+    all().into_iter().find(|day| day.number() == number)
+}
+
+/// A day whose solution can be watched in the terminal.
+pub trait Animation {
+    fn number(&self) -> u8;
+    fn params(&self) -> Params;
+    /// Plays the frames for `input`, returning how many were shown.
+    fn play(
+        &self,
+        input: &str,
+        params: &Params,
+        player: &mut Player,
+    ) -> Result<io::Result<usize>, ParseError>;
+}
+
+struct Animated<S>(PhantomData<S>);
+
+impl<S: Visualize> Animation for Animated<S> {
+    fn number(&self) -> u8 {
+        S::DAY
+    }
+
+    fn params(&self) -> Params {
+        Params::defaults(S::PARAMS)
+    }
+
+    fn play(
+        &self,
+        input: &str,
+        params: &Params,
+        player: &mut Player,
+    ) -> Result<io::Result<usize>, ParseError> {
+        let parsed = S::parse(input)?;
+        Ok(player.play(S::frames(&parsed, params)))
+    }
+}
+
+fn animated<S: Visualize + 'static>() -> Box<dyn Animation> {
+    Box::new(Animated::<S>(PhantomData))
+}
+
+pub fn animations() -> Vec<Box<dyn Animation>> {
+    vec![
+        animated::<day9::Day9>(),
+        animated::<day12::Day12>(),
+        animated::<day14::Day14>(),
+    ]
+}
+
+pub fn find_animation(number: u8) -> Option<Box<dyn Animation>> {
+    animations()
+        .into_iter()
+        .find(|animation| animation.number() == number)
+}
+
+/// A day whose parsed input can be explored at a prompt.
+pub trait Explorer {
+    fn number(&self) -> u8;
+    fn params(&self) -> Params;
+    /// Parses `input` and hands it to `repl` until the user is done.
+    fn explore(
+        &self,
+        input: &str,
+        params: &Params,
+        repl: &mut Repl,
+    ) -> Result<io::Result<()>, ParseError>;
+}
+
+struct Explored<S>(PhantomData<S>);
+
+impl<S: Explore> Explorer for Explored<S> {
+    fn number(&self) -> u8 {
+        S::DAY
+    }
+
+    fn params(&self) -> Params {
+        Params::defaults(S::PARAMS)
+    }
+
+    fn explore(
+        &self,
+        input: &str,
+        params: &Params,
+        repl: &mut Repl,
+    ) -> Result<io::Result<()>, ParseError> {
+        let parsed = S::parse(input)?;
+        Ok(repl.run(
+            S::DAY,
+            || S::explore(&parsed, params),
+            |part| match part {
+                Part::One => describe(&S::part_1(&parsed, params)),
+                Part::Two => describe(&S::part_2(&parsed, params)),
+            },
+        ))
+    }
+}
+
+fn explored<S: Explore + 'static>() -> Box<dyn Explorer> {
+    Box::new(Explored::<S>(PhantomData))
+}
+
+pub fn explorers() -> Vec<Box<dyn Explorer>> {
+    vec![
+        explored::<day5::Day5>(),
+        explored::<day7::Day7>(),
+        explored::<day12::Day12>(),
+        explored::<day14::Day14>(),
+    ]
+}
+
+pub fn find_explorer(number: u8) -> Option<Box<dyn Explorer>> {
+    explorers()
+        .into_iter()
+        .find(|explorer| explorer.number() == number)
+}
+
+/// The directory holding a day's crate and puzzle files, relative to the workspace root.
+pub fn directory(day: u8) -> PathBuf {
+    PathBuf::from(format!("day{}", day))
+}
+
+#[test]
+fn test_run_example() {
+    let input = include_str!("../../day7/example.txt");
+    let day = find(7).unwrap();
+    let result = day
+        .run(input, &[Part::One, Part::Two], &day.params())
+        .unwrap();
+    assert_eq!(result.day, 7);
+    assert_eq!(result.parts[0].answer.as_deref(), Some("95437"));
+    assert_eq!(result.parts[1].answer.as_deref(), Some("24933642"));
+}
+
+#[test]
+fn test_play_animation() {
+    let input = include_str!("../../day14/example.txt");
+    let mut player = Player::new(1000.0).headless();
+    let animation = find_animation(14).unwrap();
+    let shown = animation
+        .play(input, &animation.params(), &mut player)
+        .unwrap()
+        .unwrap();
+    assert_eq!(shown, 25);
+    assert!(find_animation(1).is_none());
+}
+
+#[test]
+fn test_explore() {
+    use std::{cell::RefCell, rc::Rc};
+
+    #[derive(Clone, Default)]
+    struct Shared(Rc<RefCell<Vec<u8>>>);
+
+    impl io::Write for Shared {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.borrow_mut().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let input = include_str!("../../day14/example.txt");
+    let commands =
+        "step\nstep 100\nquery grains\nreset\n\nquery at\nshow\npart 2\nfly\nquit\nstep\n";
+    let out = Shared::default();
+    let mut repl =
+        Repl::new(commands.as_bytes(), out.clone()).palette(aoc_common::vis::Palette::mono());
+    let explorer = find_explorer(14).unwrap();
+    explorer
+        .explore(input, &explorer.params(), &mut repl)
+        .unwrap()
+        .unwrap();
+    let out = String::from_utf8(out.0.take()).unwrap();
+    let replies: Vec<&str> = out.split("day 14> ").skip(1).collect();
+    assert_eq!(
+        replies[..6],
+        [
+            "step 1: grain 1 rests at 500,8\n",
+            "finished after 24 steps\n",
+            "24\n",
+            "back to the start\n",
+            "step 1: grain 1 rests at 500,8\n",
+            "unknown query `at`\n",
+        ]
+    );
+    assert!(replies[6].starts_with("......+...\n"));
+    assert_eq!(replies[7], "part 2: 93\n");
+    assert_eq!(replies[8], "unknown command `fly`, try `help`\n");
+    assert_eq!(replies.len(), 10);
+    assert!(find_explorer(1).is_none());
+}
+
+#[test]
+fn test_generated_inputs_solve() {
+    for day in all() {
+        for seed in 0..3 {
+            let input = day.generate(seed, Some(12));
+            let mut params = day.params();
+            if day.number() == 15 {
+                // Part 2 scans every row up to `max`, which takes most of a minute at the
+                // real size. Day 15's generator tests its single gap on its own.
+                params.set("max", 400).unwrap();
+            }
+            if let Err(e) = day.run(&input, &[Part::One, Part::Two], &params) {
+                panic!(
+                    "day {} seed {}: {}\n{}",
+                    day.number(),
+                    seed,
+                    e.diagnostic(&input),
+                    input
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,324 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+
+pub const YEAR: u16 = 2022;
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = concat!("aoc-2022-runner/", env!("CARGO_PKG_VERSION"));
+
+/// Settings for talking to the Advent of Code site, read from `~/.config/aoc/config.toml`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// The value of the site's `session` cookie.
+    pub session: Option<String>,
+    pub base_url: Option<String>,
+    pub cache_dir: Option<PathBuf>,
+}
+
+impl Config {
+    /// Reads the config file, if there is one, letting `AOC_SESSION`, `AOC_BASE_URL` and
+    /// `AOC_CACHE_DIR` override it.
+    pub fn load() -> Result<Self> {
+        let mut config = match config_path() {
+            Some(path) => Config::load_file(&path)?,
+            None => Config::default(),
+        };
+        if let Ok(session) = env::var("AOC_SESSION") {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = env::var("AOC_BASE_URL") {
+            config.base_url = Some(base_url);
+        }
+        if let Some(cache_dir) = env::var_os("AOC_CACHE_DIR") {
+            config.cache_dir = Some(PathBuf::from(cache_dir));
+        }
+        Ok(config)
+    }
+
+    fn load_file(path: &Path) -> Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(text) => {
+                toml::from_str(&text).with_context(|| format!("parsing {}", path.display()))
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(e) => Err(e).with_context(|| format!("reading {}", path.display())),
+        }
+    }
+
+    /// The session token, accepting a pasted `session=...` cookie too.
+    pub fn session(&self) -> Result<&str> {
+        let Some(session) = &self.session else {
+            bail!("no session token is set; set AOC_SESSION or `session` in the config file");
+        };
+        let session = session.trim();
+        Ok(session.strip_prefix("session=").unwrap_or(session))
+    }
+
+    pub fn base_url(&self) -> &str {
+        self.base_url
+            .as_deref()
+            .unwrap_or(DEFAULT_BASE_URL)
+            .trim_end_matches('/')
+    }
+
+    pub fn cache(&self) -> Cache {
+        let dir = self.cache_dir.clone().unwrap_or_else(|| {
+            home_dir("XDG_CACHE_HOME", ".cache")
+                .map(|dir| dir.join("aoc"))
+                .unwrap_or_else(|| PathBuf::from("target/aoc-cache"))
+        });
+        Cache { dir }
+    }
+}
+
+/// `$AOC_CONFIG`, or `config.toml` in the user's config directory.
+fn config_path() -> Option<PathBuf> {
+    env::var_os("AOC_CONFIG")
+        .map(PathBuf::from)
+        .or_else(|| home_dir("XDG_CONFIG_HOME", ".config").map(|dir| dir.join("aoc/config.toml")))
+}
+
+/// The XDG directory in `var`, falling back to `fallback` under `$HOME`.
+fn home_dir(var: &str, fallback: &str) -> Option<PathBuf> {
+    env::var_os(var)
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(fallback)))
+}
+
+pub fn agent() -> ureq::Agent {
+    ureq::AgentBuilder::new()
+        .user_agent(USER_AGENT)
+        .timeout(Duration::from_secs(30))
+        .build()
+}
+
+pub fn unix_time() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+/// What is known about a cached input, stored beside it as `meta.json`.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Metadata {
+    pub year: u16,
+    pub day: u8,
+    pub url: String,
+    /// Seconds since the Unix epoch.
+    pub fetched_at: u64,
+    pub bytes: usize,
+}
+
+/// Downloaded inputs, kept as `<dir>/<year>/dayN/input.txt`.
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn input_path(&self, day: u8) -> PathBuf {
+        self.day_dir(day).join("input.txt")
+    }
+
+    pub fn submissions_path(&self, day: u8) -> PathBuf {
+        self.day_dir(day).join("submissions.json")
+    }
+
+    /// The site limits answers per account, so one cooldown covers every day.
+    pub fn cooldown_path(&self) -> PathBuf {
+        self.dir.join("cooldown.json")
+    }
+
+    fn day_dir(&self, day: u8) -> PathBuf {
+        self.dir.join(YEAR.to_string()).join(format!("day{}", day))
+    }
+
+    /// An input only counts as cached once its metadata has been written after it.
+    pub fn get(&self, day: u8) -> Result<Option<String>> {
+        if !self.day_dir(day).join("meta.json").exists() {
+            return Ok(None);
+        }
+        let path = self.input_path(day);
+        match fs::read_to_string(&path) {
+            Ok(input) => Ok(Some(input)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e).with_context(|| format!("reading {}", path.display())),
+        }
+    }
+
+    pub fn metadata(&self, day: u8) -> Result<Metadata> {
+        let path = self.day_dir(day).join("meta.json");
+        let text =
+            fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))?;
+        serde_json::from_str(&text).with_context(|| format!("parsing {}", path.display()))
+    }
+
+    fn put(&self, input: &str, metadata: &Metadata) -> Result<()> {
+        let dir = self.day_dir(metadata.day);
+        fs::create_dir_all(&dir).with_context(|| format!("creating {}", dir.display()))?;
+        let path = self.input_path(metadata.day);
+        fs::write(&path, input).with_context(|| format!("writing {}", path.display()))?;
+        let path = dir.join("meta.json");
+        let json = serde_json::to_string_pretty(metadata)?;
+        fs::write(&path, json + "\n").with_context(|| format!("writing {}", path.display()))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Cached(String),
+    Downloaded(String),
+}
+
+impl Fetched {
+    pub fn input(&self) -> &str {
+        match self {
+            Fetched::Cached(input) | Fetched::Downloaded(input) => input,
+        }
+    }
+}
+
+/// Downloads puzzle inputs, going to the network only for days that aren't cached yet.
+pub struct Fetcher {
+    config: Config,
+    cache: Cache,
+    agent: ureq::Agent,
+}
+
+impl Fetcher {
+    pub fn new(config: Config) -> Self {
+        Fetcher {
+            cache: config.cache(),
+            config,
+            agent: agent(),
+        }
+    }
+
+    pub fn cache(&self) -> &Cache {
+        &self.cache
+    }
+
+    pub fn fetch(&self, day: u8) -> Result<Fetched> {
+        if let Some(input) = self.cache.get(day)? {
+            return Ok(Fetched::Cached(input));
+        }
+        let session = self.config.session()?;
+        let url = format!("{}/{}/day/{}/input", self.config.base_url(), YEAR, day);
+        let response = match self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", session))
+            .call()
+        {
+            Ok(response) => response,
+            Err(ureq::Error::Status(400 | 401 | 403, _)) => {
+                bail!("{} rejected the session token; it may have expired", url)
+            }
+            Err(ureq::Error::Status(404, _)) => {
+                bail!("day {} has no input at {} yet", day, url)
+            }
+            Err(e) => return Err(e).with_context(|| format!("fetching {}", url)),
+        };
+        let input = response
+            .into_string()
+            .with_context(|| format!("reading the response from {}", url))?;
+        let metadata = Metadata {
+            year: YEAR,
+            day,
+            url,
+            fetched_at: unix_time(),
+            bytes: input.len(),
+        };
+        self.cache.put(&input, &metadata)?;
+        Ok(Fetched::Downloaded(input))
+    }
+}
+
+#[cfg(test)]
+fn mock_server(responses: usize) -> (String, std::thread::JoinHandle<Vec<Option<String>>>) {
+    let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", server.server_addr().to_ip().unwrap());
+    let handle = std::thread::spawn(move || {
+        let mut cookies = Vec::new();
+        for request in server.incoming_requests().take(responses) {
+            let cookie = request
+                .headers()
+                .iter()
+                .find(|header| header.field.equiv("Cookie"))
+                .map(|header| header.value.to_string());
+            let response = match (request.url(), cookie.as_deref()) {
+                ("/2022/day/1/input", Some("session=secret")) => {
+                    tiny_http::Response::from_string("1000\n2000\n")
+                }
+                (_, Some("session=secret")) => {
+                    tiny_http::Response::from_string("Not Found").with_status_code(404)
+                }
+                _ => tiny_http::Response::from_string("Please log in").with_status_code(400),
+            };
+            cookies.push(cookie);
+            request.respond(response).unwrap();
+        }
+        cookies
+    });
+    (base_url, handle)
+}
+
+#[cfg(test)]
+fn test_config(name: &str, base_url: &str, session: Option<&str>) -> Config {
+    let dir = env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    Config {
+        session: session.map(str::to_owned),
+        base_url: Some(base_url.to_owned()),
+        cache_dir: Some(dir),
+    }
+}
+
+#[test]
+fn test_fetch_caches_input() {
+    let (base_url, server) = mock_server(1);
+    let config = test_config("cache", &base_url, Some("session=secret\n"));
+    let cache_dir = config.cache_dir.clone().unwrap();
+    let fetcher = Fetcher::new(config);
+    let input = "1000\n2000\n".to_owned();
+    assert_eq!(
+        fetcher.fetch(1).unwrap(),
+        Fetched::Downloaded(input.clone())
+    );
+    assert_eq!(fetcher.fetch(1).unwrap(), Fetched::Cached(input.clone()));
+    assert_eq!(server.join().unwrap(), [Some("session=secret".to_owned())]);
+
+    let metadata = fetcher.cache().metadata(1).unwrap();
+    assert_eq!(metadata.url, format!("{}/2022/day/1/input", base_url));
+    assert_eq!(metadata.bytes, input.len());
+
+    // Cached days don't need a session, or a server.
+    let offline = Fetcher::new(Config {
+        session: None,
+        base_url: Some("http://127.0.0.1:9".to_owned()),
+        cache_dir: Some(cache_dir.clone()),
+    });
+    assert_eq!(offline.fetch(1).unwrap(), Fetched::Cached(input));
+    assert!(offline.fetch(2).is_err());
+    fs::remove_dir_all(cache_dir).unwrap();
+}
+
+#[test]
+fn test_fetch_errors() {
+    let (base_url, server) = mock_server(2);
+    let rejected = Fetcher::new(test_config("rejected", &base_url, Some("stale")));
+    let error = rejected.fetch(1).unwrap_err().to_string();
+    assert!(error.contains("rejected the session token"), "{}", error);
+    assert_eq!(rejected.cache().get(1).unwrap(), None);
+
+    let missing = Fetcher::new(test_config("missing", &base_url, Some("secret")));
+    let error = missing.fetch(25).unwrap_err().to_string();
+    assert!(error.contains("no input"), "{}", error);
+    server.join().unwrap();
+}
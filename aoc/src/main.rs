@@ -0,0 +1,718 @@
+mod bench;
+mod days;
+mod fetch;
+mod output;
+mod pool;
+mod repl;
+mod report;
+mod scaffold;
+mod submit;
+mod verify;
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+use aoc_common::{
+    vis::{Palette, Player},
+    InputSource, Params, ParamsFile, ParseError,
+};
+use clap::{builder::PossibleValuesParser, Args, Parser, Subcommand};
+
+use crate::{
+    bench::{Baseline, DayStats},
+    days::{Day, DayResult, Part},
+    fetch::{Config, Fetched, Fetcher},
+    output::{Format, SummaryRow},
+    pool::Task,
+    repl::Repl,
+    submit::{Submitted, Verdict},
+    verify::Outcome,
+};
+
+const DEFAULT_BASELINE: &str = "target/aoc-bench-baseline.json";
+const PARAMS_FILE: &str = "aoc.toml";
+const DEFAULT_TIMEOUT: u64 = 60;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solutions for one day, or for every day with `all`
+    Run {
+        day: DaySelection,
+        /// Only run this part (1 or 2)
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        #[command(flatten)]
+        input: InputArgs,
+        #[command(flatten)]
+        params: ParamArgs,
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        #[command(flatten)]
+        pool: PoolArgs,
+    },
+    /// Check solutions against the known answers in dayN/answers.toml
+    Verify {
+        #[arg(default_value = "all")]
+        day: DaySelection,
+    },
+    /// Time parsing and each part over repeated runs
+    Bench {
+        #[arg(default_value = "all")]
+        day: DaySelection,
+        /// Number of times to repeat each measurement
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        runs: u32,
+        #[command(flatten)]
+        input: InputArgs,
+        #[command(flatten)]
+        params: ParamArgs,
+        /// Save the results as a baseline, keeping other days already in the file
+        #[arg(long, num_args = 0..=1, default_missing_value = DEFAULT_BASELINE)]
+        save: Option<PathBuf>,
+        /// Compare the results against a saved baseline
+        #[arg(long, num_args = 0..=1, default_missing_value = DEFAULT_BASELINE)]
+        compare: Option<PathBuf>,
+    },
+    /// Print a random puzzle input for a day
+    Gen {
+        day: u8,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// How big an input to make; what this counts depends on the day
+        #[arg(long)]
+        size: Option<usize>,
+    },
+    /// Create the crate for a new day and add it to the workspace, runner and fuzz targets
+    New {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Download a day's puzzle input into dayN/input.txt, unless it is already cached
+    Fetch {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Compute a day's answer and submit it, unless it is already known to be wrong
+    Submit {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Puzzle input file or day directory, or `-` for stdin; defaults to dayN/input.txt
+        #[arg(long)]
+        input: Option<String>,
+        #[command(flatten)]
+        params: ParamArgs,
+    },
+    /// Run every day and write the answers, timings and pictures to a single HTML page
+    Report {
+        /// Where to write the page
+        #[arg(long, value_name = "PATH")]
+        html: PathBuf,
+        #[command(flatten)]
+        pool: PoolArgs,
+    },
+    /// Animate a solution working through its input (days 9, 12 and 14)
+    Vis {
+        day: u8,
+        #[command(flatten)]
+        input: InputArgs,
+        #[command(flatten)]
+        params: ParamArgs,
+        #[arg(long, default_value_t = 30.0)]
+        fps: f64,
+        #[arg(long, default_value = "default", value_parser = PossibleValuesParser::new(Palette::NAMES))]
+        palette: String,
+        /// Also write every frame, uncoloured, to this file
+        #[arg(long)]
+        record: Option<PathBuf>,
+        /// Start paused on the first frame
+        #[arg(long)]
+        paused: bool,
+        /// Don't draw anything, only record
+        #[arg(long, requires = "record")]
+        headless: bool,
+    },
+    /// Step through a day's solution at a prompt, showing and querying its state (days 5, 7,
+    /// 12 and 14)
+    Repl {
+        day: u8,
+        #[command(flatten)]
+        input: InputArgs,
+        #[command(flatten)]
+        params: ParamArgs,
+        #[arg(long, default_value = "default", value_parser = PossibleValuesParser::new(Palette::NAMES))]
+        palette: String,
+    },
+}
+
+#[derive(Args, Default)]
+struct InputArgs {
+    /// Puzzle input file or day directory, or `-` for stdin; defaults to dayN/input.txt
+    #[arg(long)]
+    input: Option<String>,
+    /// Use the day's example.txt instead of its input
+    #[arg(long, conflicts_with = "input")]
+    example: bool,
+}
+
+impl InputArgs {
+    fn source(&self, day: u8) -> InputSource {
+        match (&self.input, self.example) {
+            (_, true) => InputSource::Example,
+            (Some(path), false) => InputSource::parse(path),
+            (None, false) => InputSource::Path(days::directory(day)),
+        }
+    }
+}
+
+fn params_file() -> Result<ParamsFile> {
+    ParamsFile::load(Path::new(PARAMS_FILE)).map_err(anyhow::Error::msg)
+}
+
+#[derive(Args, Default)]
+struct ParamArgs {
+    /// Set one of the day's puzzle parameters, overriding aoc.toml
+    #[arg(long = "param", value_name = "NAME=VALUE")]
+    params: Vec<String>,
+}
+
+impl ParamArgs {
+    /// The day's defaults, updated from aoc.toml and then the command line.
+    fn resolve(
+        &self,
+        file: &ParamsFile,
+        day: u8,
+        mut params: Params,
+        source: &InputSource,
+    ) -> Result<Params> {
+        file.apply(day, *source == InputSource::Example, &mut params)
+            .map_err(anyhow::Error::msg)?;
+        for assignment in &self.params {
+            params
+                .assign(assignment)
+                .map_err(|e| anyhow!("--param {}: {}", assignment, e))?;
+        }
+        Ok(params)
+    }
+}
+
+/// How days are spread over threads when running them all.
+#[derive(Args)]
+struct PoolArgs {
+    /// How many days to run at once; defaults to the number of CPUs
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..))]
+    jobs: Option<u32>,
+    /// Seconds each day may take before it is given up on [default: 60]
+    #[arg(long, value_parser = clap::value_parser!(u64).range(1..))]
+    timeout: Option<u64>,
+}
+
+impl PoolArgs {
+    fn is_set(&self) -> bool {
+        self.jobs.is_some() || self.timeout.is_some()
+    }
+
+    fn jobs(&self) -> usize {
+        match self.jobs {
+            Some(jobs) => jobs as usize,
+            None => thread::available_parallelism().map_or(1, |n| n.get()),
+        }
+    }
+
+    fn timeout(&self) -> Duration {
+        Duration::from_secs(self.timeout.unwrap_or(DEFAULT_TIMEOUT))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum DaySelection {
+    All,
+    Day(u8),
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(DaySelection::All);
+        }
+        s.parse()
+            .map(DaySelection::Day)
+            .map_err(|_| format!("expected a day number or `all`, got `{}`", s))
+    }
+}
+
+impl DaySelection {
+    fn days(self) -> Result<Vec<Box<dyn Day>>> {
+        match self {
+            DaySelection::All => Ok(days::all()),
+            DaySelection::Day(number) => {
+                Ok(vec![days::find(number).ok_or_else(|| {
+                    anyhow!("no solution for day {}", number)
+                })?])
+            }
+        }
+    }
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            params,
+            format,
+            pool,
+        } => {
+            let file = params_file()?;
+            let parts = match part {
+                None => vec![Part::One, Part::Two],
+                Some(1) => vec![Part::One],
+                Some(_) => vec![Part::Two],
+            };
+            match day {
+                DaySelection::All => {
+                    if input.input.is_some() {
+                        bail!("--input cannot be used with `all`");
+                    }
+                    if !params.params.is_empty() {
+                        bail!("--param cannot be used with `all`");
+                    }
+                    let tasks = day_tasks(&file, &input, &params, move |day, input, params| {
+                        day.run(input, &parts, params)
+                    })?;
+                    run_all(tasks, &pool, format)?;
+                }
+                DaySelection::Day(number) => {
+                    if pool.is_set() {
+                        bail!("--jobs and --timeout can only be used with `all`");
+                    }
+                    let day = days::find(number)
+                        .ok_or_else(|| anyhow!("no solution for day {}", number))?;
+                    let source = input.source(number);
+                    let params = params.resolve(&file, number, day.params(), &source)?;
+                    run(day.as_ref(), &source, &parts, &params, format)?;
+                }
+            }
+        }
+        Command::Verify { day } => verify(&day.days()?, &params_file()?)?,
+        Command::Bench {
+            day,
+            runs,
+            input,
+            params,
+            save,
+            compare,
+        } => {
+            if input.input.is_some() && matches!(day, DaySelection::All) {
+                bail!("--input cannot be used with `all`");
+            }
+            if !params.params.is_empty() && matches!(day, DaySelection::All) {
+                bail!("--param cannot be used with `all`");
+            }
+            bench(
+                &day.days()?,
+                &input,
+                &params,
+                runs as usize,
+                save.as_deref(),
+                compare.as_deref(),
+            )?;
+        }
+        Command::Gen { day, seed, size } => {
+            let day = days::find(day).ok_or_else(|| anyhow!("no solution for day {}", day))?;
+            print!("{}", day.generate(seed, size));
+        }
+        Command::Report { html, pool } => report(&html, &pool)?,
+        Command::New { day } => new(day)?,
+        Command::Fetch { day } => fetch(day)?,
+        Command::Submit {
+            day,
+            part,
+            input,
+            params,
+        } => submit(day, part, input.as_deref(), &params)?,
+        Command::Vis {
+            day,
+            input,
+            params,
+            fps,
+            palette,
+            record,
+            paused,
+            headless,
+        } => {
+            if fps <= 0.0 {
+                bail!("--fps must be positive");
+            }
+            let palette = Palette::named(&palette).expect("clap only accepts known palettes");
+            let mut player = Player::new(fps).palette(palette).paused(paused);
+            if headless {
+                player = player.headless();
+            } else {
+                player = player.with_controls();
+            }
+            if let Some(path) = &record {
+                player = player
+                    .record_to(path)
+                    .with_context(|| format!("creating {}", path.display()))?;
+            }
+            vis(
+                day,
+                &input.source(day),
+                &params,
+                &mut player,
+                record.as_deref(),
+            )?;
+        }
+        Command::Repl {
+            day,
+            input,
+            params,
+            palette,
+        } => {
+            let palette = Palette::named(&palette).expect("clap only accepts known palettes");
+            repl(day, &input.source(day), &params, palette)?;
+        }
+    }
+    Ok(())
+}
+
+fn verify(days: &[Box<dyn Day>], file: &ParamsFile) -> Result<()> {
+    let (mut passed, mut mismatched, mut failed) = (0, 0, 0);
+    for day in days {
+        let Some(checks) = verify::verify_day(day.as_ref(), file)? else {
+            println!("day {:>2}: no known answers", day.number());
+            continue;
+        };
+        for check in checks.iter() {
+            verify::print_check(check);
+            match check.outcome {
+                Outcome::Pass => passed += 1,
+                Outcome::Mismatch { .. } => mismatched += 1,
+                Outcome::Fail(_) => failed += 1,
+            }
+        }
+    }
+    println!(
+        "{} passed, {} mismatched, {} failed",
+        passed, mismatched, failed
+    );
+    if mismatched + failed > 0 {
+        bail!("verification failed");
+    }
+    Ok(())
+}
+
+fn bench(
+    days: &[Box<dyn Day>],
+    input: &InputArgs,
+    params: &ParamArgs,
+    runs: usize,
+    save: Option<&Path>,
+    compare: Option<&Path>,
+) -> Result<()> {
+    let baseline = compare.map(Baseline::load).transpose()?;
+    let file = params_file()?;
+    let mut results = Vec::new();
+    for day in days {
+        let source = input.source(day.number());
+        let params = params.resolve(&file, day.number(), day.params(), &source)?;
+        let input = source.read(day.number())?;
+        let samples = day
+            .bench(&input, runs, &params)
+            .map_err(|e| parse_error(day.number(), &source, &input, e))?;
+        let stats = DayStats::from_samples(&samples).context("no samples were taken")?;
+        let before = baseline
+            .as_ref()
+            .and_then(|baseline| baseline.days.get(&day.number()));
+        bench::print_stats(day.number(), &stats, before);
+        results.push((day.number(), stats));
+    }
+    if let Some(path) = save {
+        let mut saved = Baseline::load_or_default(path)?;
+        saved.days.extend(results);
+        saved.save(path)?;
+        println!("saved baseline to {}", path.display());
+    }
+    Ok(())
+}
+
+fn run(
+    day: &dyn Day,
+    source: &InputSource,
+    parts: &[Part],
+    params: &Params,
+    format: Format,
+) -> Result<()> {
+    let input = source.read(day.number())?;
+    let result = day
+        .run(&input, parts, params)
+        .map_err(|e| parse_error(day.number(), source, &input, e))?;
+    output::print_result(&result, format);
+    Ok(())
+}
+
+/// One task per day, which reads the day's input and hands it to `work` with the day's
+/// parameters.
+fn day_tasks<T: Send + 'static>(
+    file: &ParamsFile,
+    input: &InputArgs,
+    params: &ParamArgs,
+    work: impl Fn(&dyn Day, &str, &Params) -> Result<T, ParseError> + Clone + Send + 'static,
+) -> Result<Vec<Task<T>>> {
+    days::all()
+        .into_iter()
+        .map(|day| {
+            let number = day.number();
+            let source = input.source(number);
+            let params = params.resolve(file, number, day.params(), &source)?;
+            let work = work.clone();
+            Ok(Task::new(number, move || {
+                let input = source
+                    .read(number)
+                    .map_err(|e| anyhow!("day {}: {}", number, e))?;
+                work(day.as_ref(), &input, &params)
+                    .map_err(|e| parse_error(number, &source, &input, e))
+            }))
+        })
+        .collect()
+}
+
+/// Runs every day on a pool of threads, printing each day's results in order as they come
+/// in, and then a summary table.
+fn run_all(tasks: Vec<Task<DayResult>>, pool: &PoolArgs, format: Format) -> Result<()> {
+    let (jobs, timeout) = (pool.jobs(), pool.timeout());
+    let start = Instant::now();
+    let mut rows = Vec::new();
+    pool::run(tasks, jobs, timeout, |finished| {
+        let row = SummaryRow::new(finished, timeout);
+        if let Some(result) = &row.result {
+            output::print_result(result, format);
+        }
+        if let Some(error) = &row.error {
+            eprintln!("{}", error);
+        }
+        rows.push(row);
+    });
+    let failed = rows.iter().filter(|row| !row.finished()).count();
+    let summary = format!(
+        "{}{} days in {:.2?}, {} at a time, {} failed",
+        output::summary_table(&rows),
+        rows.len(),
+        start.elapsed(),
+        jobs,
+        failed
+    );
+    // Keep the JSON lines on stdout parseable.
+    match format {
+        Format::Text => println!("\n{}", summary),
+        Format::Json => eprintln!("{}", summary),
+    }
+    if failed > 0 {
+        bail!("{} of {} days did not finish", failed, rows.len());
+    }
+    Ok(())
+}
+
+fn report(path: &Path, pool: &PoolArgs) -> Result<()> {
+    let file = params_file()?;
+    let tasks = day_tasks(
+        &file,
+        &InputArgs::default(),
+        &ParamArgs::default(),
+        |day, input, params| {
+            let result = day.run(input, &[Part::One, Part::Two], params)?;
+            Ok((result, report::figure(day.number(), input, params)?))
+        },
+    )?;
+    let timeout = pool.timeout();
+    let start = Instant::now();
+    let mut days = Vec::new();
+    pool::run(tasks, pool.jobs(), timeout, |finished| {
+        let mut figure = None;
+        let finished = finished.map(|(result, day_figure)| {
+            figure = day_figure;
+            result
+        });
+        let row = SummaryRow::new(finished, timeout);
+        match &row.error {
+            Some(error) => eprintln!("{}", error),
+            None => println!("day {}: {}", row.day, row.status.name()),
+        }
+        days.push((row, figure));
+    });
+    let html = report::page(&days, start.elapsed());
+    fs::write(path, html).with_context(|| format!("writing {}", path.display()))?;
+    println!("wrote {}", path.display());
+    let failed = days.iter().filter(|(row, _)| !row.finished()).count();
+    if failed > 0 {
+        bail!("{} of {} days did not finish", failed, days.len());
+    }
+    Ok(())
+}
+
+fn new(day: u8) -> Result<()> {
+    if !Path::new(PARAMS_FILE).exists() {
+        bail!(
+            "run `aoc new` from the workspace root, where {} is",
+            PARAMS_FILE
+        );
+    }
+    let scaffolded = scaffold::create(Path::new(""), day)?;
+    for path in &scaffolded.created {
+        println!("created {}", path.display());
+    }
+    for path in &scaffolded.updated {
+        println!("updated {}", path.display());
+    }
+    println!(
+        "fill in day{}/example.txt and the answers in its test, then `aoc fetch {}`",
+        day, day
+    );
+    Ok(())
+}
+
+fn fetch(day: u8) -> Result<()> {
+    let fetcher = Fetcher::new(Config::load()?);
+    let fetched = fetcher.fetch(day)?;
+    let cached = fetcher.cache().input_path(day);
+    match &fetched {
+        Fetched::Cached(_) => println!(
+            "day {}: using {}, cached from {}",
+            day,
+            cached.display(),
+            fetcher.cache().metadata(day)?.url
+        ),
+        Fetched::Downloaded(input) => println!(
+            "day {}: downloaded {} bytes to {}",
+            day,
+            input.len(),
+            cached.display()
+        ),
+    }
+    let directory = days::directory(day);
+    if !directory.is_dir() {
+        return Ok(());
+    }
+    let path = directory.join("input.txt");
+    match fs::read_to_string(&path) {
+        // `aoc new` leaves an empty input.txt to be filled in.
+        Ok(existing) if existing.is_empty() => {
+            fs::write(&path, fetched.input())
+                .with_context(|| format!("writing {}", path.display()))?;
+            println!("wrote {}", path.display());
+        }
+        Ok(existing) if existing == fetched.input() => {
+            println!("{} is up to date", path.display())
+        }
+        Ok(_) => println!(
+            "{} differs from the downloaded input; leaving it alone",
+            path.display()
+        ),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            fs::write(&path, fetched.input())
+                .with_context(|| format!("writing {}", path.display()))?;
+            println!("wrote {}", path.display());
+        }
+        Err(e) => return Err(e).with_context(|| format!("reading {}", path.display())),
+    }
+    Ok(())
+}
+
+fn submit(day: u8, part: u8, input: Option<&str>, params: &ParamArgs) -> Result<()> {
+    let solution = days::find(day).ok_or_else(|| anyhow!("no solution for day {}", day))?;
+    let source = input.map_or_else(
+        || InputSource::Path(days::directory(day)),
+        InputSource::parse,
+    );
+    let file = params_file()?;
+    let params = params.resolve(&file, day, solution.params(), &source)?;
+    let text = source.read(day)?;
+    let parts = [if part == 1 { Part::One } else { Part::Two }];
+    let result = solution
+        .run(&text, &parts, &params)
+        .map_err(|e| parse_error(day, &source, &text, e))?;
+    let answer = result.parts[0]
+        .answer
+        .clone()
+        .with_context(|| format!("day {} part {} found no answer", day, part))?;
+    match submit::submit(&Config::load()?, day, part, &answer, fetch::unix_time())? {
+        Submitted::AlreadyAccepted => {
+            println!("day {} part {}: {} was already accepted", day, part, answer)
+        }
+        Submitted::Sent(attempt) => {
+            let verdict = attempt.verdict.describe();
+            println!("day {} part {}: {}: {}", day, part, answer, verdict);
+            if let Some(wait_until) = attempt.wait_until {
+                println!(
+                    "wait {}s before submitting again",
+                    wait_until - attempt.submitted_at
+                );
+            }
+            if attempt.verdict != Verdict::Correct {
+                bail!("the answer was not accepted");
+            }
+        }
+    }
+    Ok(())
+}
+
+fn vis(
+    day: u8,
+    source: &InputSource,
+    params: &ParamArgs,
+    player: &mut Player,
+    record: Option<&Path>,
+) -> Result<()> {
+    let animation =
+        days::find_animation(day).ok_or_else(|| anyhow!("no animation for day {}", day))?;
+    let file = params_file()?;
+    let params = params.resolve(&file, day, animation.params(), source)?;
+    let input = source.read(day)?;
+    let shown = animation
+        .play(&input, &params, player)
+        .map_err(|e| parse_error(day, source, &input, e))?
+        .context("playing animation")?;
+    if let Some(record) = record {
+        println!("recorded {} frames to {}", shown, record.display());
+    }
+    Ok(())
+}
+
+fn repl(day: u8, source: &InputSource, params: &ParamArgs, palette: Palette) -> Result<()> {
+    if *source == InputSource::Stdin {
+        bail!("the puzzle input cannot come from stdin, which is where commands are read from");
+    }
+    let explorer =
+        days::find_explorer(day).ok_or_else(|| anyhow!("no explorer for day {}", day))?;
+    let file = params_file()?;
+    let params = params.resolve(&file, day, explorer.params(), source)?;
+    let input = source.read(day)?;
+    let mut repl = Repl::new(io::stdin().lock(), io::stdout()).palette(palette);
+    explorer
+        .explore(&input, &params, &mut repl)
+        .map_err(|e| parse_error(day, source, &input, e))?
+        .context("reading commands")
+}
+
+fn parse_error(day: u8, source: &InputSource, input: &str, e: ParseError) -> anyhow::Error {
+    anyhow!("day {}: {}: {}", day, source.name(day), e.diagnostic(input))
+}
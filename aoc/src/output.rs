@@ -0,0 +1,261 @@
+use std::time::Duration;
+
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::{
+    days::DayResult,
+    pool::{Finished, Outcome},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    Text,
+    /// One JSON object per line for every part that was run
+    Json,
+}
+
+/// The JSON form of one part's result. Field names are relied on by dashboards, so only ever
+/// add to them.
+#[derive(Debug, Serialize)]
+struct JsonPart<'a> {
+    day: u8,
+    part: u8,
+    answer: Option<&'a str>,
+    parse_ns: u64,
+    elapsed_ns: u64,
+    diagnostics: Option<&'a str>,
+}
+
+pub fn print_result(result: &DayResult, format: Format) {
+    match format {
+        Format::Text => print_text(result),
+        Format::Json => {
+            for line in json_lines(result) {
+                println!("{}", line);
+            }
+        }
+    }
+}
+
+fn print_text(result: &DayResult) {
+    println!("day {} (parse: {:?})", result.day, result.parse_elapsed);
+    for part in result.parts.iter() {
+        match part.answer.as_deref() {
+            Some(answer) if answer.contains('\n') => {
+                println!("  part {} ({:?}):", part.part.number(), part.elapsed);
+                print_indented(answer);
+            }
+            answer => println!(
+                "  part {}: {} ({:?})",
+                part.part.number(),
+                answer.unwrap_or("no answer"),
+                part.elapsed
+            ),
+        }
+        if let Some(diagnostics) = part.diagnostics.as_deref() {
+            print_indented(diagnostics);
+        }
+    }
+}
+
+/// How a day went in `run all`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    /// It ran, but a part found no answer.
+    NoAnswer,
+    Failed,
+    Panicked,
+    TimedOut,
+}
+
+impl Status {
+    pub fn name(self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::NoAnswer => "no answer",
+            Status::Failed => "failed",
+            Status::Panicked => "panicked",
+            Status::TimedOut => "timed out",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct SummaryRow {
+    pub day: u8,
+    pub status: Status,
+    /// Only there for days that ran to the end.
+    pub result: Option<DayResult>,
+    /// Why the day did not run to the end.
+    pub error: Option<String>,
+    pub elapsed: Duration,
+}
+
+impl SummaryRow {
+    pub fn new(finished: Finished<DayResult>, timeout: Duration) -> Self {
+        let day = finished.day;
+        let (status, result, error) = match finished.outcome {
+            Outcome::Done(result) => {
+                let status = if result.parts.iter().all(|part| part.answer.is_some()) {
+                    Status::Ok
+                } else {
+                    Status::NoAnswer
+                };
+                (status, Some(result), None)
+            }
+            Outcome::Failed(e) => (Status::Failed, None, Some(format!("{:#}", e))),
+            Outcome::Panicked(message) => (
+                Status::Panicked,
+                None,
+                Some(format!("day {}: panicked: {}", day, message)),
+            ),
+            Outcome::TimedOut => (
+                Status::TimedOut,
+                None,
+                Some(format!("day {}: timed out after {:?}", day, timeout)),
+            ),
+        };
+        SummaryRow {
+            day,
+            status,
+            result,
+            error,
+            elapsed: finished.elapsed,
+        }
+    }
+
+    /// Whether the day ran to the end, even if it found no answer.
+    pub fn finished(&self) -> bool {
+        matches!(self.status, Status::Ok | Status::NoAnswer)
+    }
+}
+
+/// A table of every day's status and timings, one row per day.
+pub fn summary_table(rows: &[SummaryRow]) -> String {
+    let mut table = format!(
+        "{:>3}  {:<9}  {:>10}  {:>10}  {:>10}  {:>10}\n",
+        "day", "status", "parse", "part 1", "part 2", "total"
+    );
+    for row in rows {
+        let time = |duration: Option<Duration>| {
+            duration.map_or_else(|| "-".to_owned(), |d| format!("{:.2?}", d))
+        };
+        let part = |number: u8| {
+            let parts = row.result.as_ref().map(|result| result.parts.iter());
+            time(parts.and_then(|mut parts| {
+                parts
+                    .find(|part| part.part.number() == number)
+                    .map(|part| part.elapsed)
+            }))
+        };
+        table.push_str(&format!(
+            "{:>3}  {:<9}  {:>10}  {:>10}  {:>10}  {:>10}\n",
+            row.day,
+            row.status.name(),
+            time(row.result.as_ref().map(|result| result.parse_elapsed)),
+            part(1),
+            part(2),
+            time(Some(row.elapsed))
+        ));
+    }
+    table
+}
+
+fn print_indented(text: &str) {
+    for line in text.lines() {
+        println!("    {}", line);
+    }
+}
+
+fn json_lines(result: &DayResult) -> Vec<String> {
+    result
+        .parts
+        .iter()
+        .map(|part| {
+            let json = JsonPart {
+                day: result.day,
+                part: part.part.number(),
+                answer: part.answer.as_deref(),
+                parse_ns: nanos(result.parse_elapsed),
+                elapsed_ns: nanos(part.elapsed),
+                diagnostics: part.diagnostics.as_deref(),
+            };
+            serde_json::to_string(&json).expect("results serialize to JSON")
+        })
+        .collect()
+}
+
+fn nanos(duration: Duration) -> u64 {
+    duration.as_nanos().try_into().unwrap_or(u64::MAX)
+}
+
+#[test]
+fn test_json_lines() {
+    use crate::days::{Part, PartResult};
+
+    let result = DayResult {
+        day: 10,
+        parse_elapsed: Duration::from_nanos(1500),
+        parts: vec![
+            PartResult {
+                part: Part::One,
+                answer: Some("13140".to_owned()),
+                diagnostics: None,
+                elapsed: Duration::from_micros(2),
+            },
+            PartResult {
+                part: Part::Two,
+                answer: None,
+                diagnostics: Some("ab\ncd".to_owned()),
+                elapsed: Duration::from_nanos(7),
+            },
+        ],
+    };
+    assert_eq!(
+        json_lines(&result),
+        vec![
+            r#"{"day":10,"part":1,"answer":"13140","parse_ns":1500,"elapsed_ns":2000,"diagnostics":null}"#,
+            r#"{"day":10,"part":2,"answer":null,"parse_ns":1500,"elapsed_ns":7,"diagnostics":"ab\ncd"}"#,
+        ]
+    );
+}
+
+#[test]
+fn test_summary_table() {
+    use crate::days::{Part, PartResult};
+
+    let result = DayResult {
+        day: 1,
+        parse_elapsed: Duration::from_micros(15),
+        parts: vec![PartResult {
+            part: Part::Two,
+            answer: Some("45000".to_owned()),
+            diagnostics: None,
+            elapsed: Duration::from_nanos(250),
+        }],
+    };
+    let rows = [
+        SummaryRow {
+            day: 1,
+            status: Status::Ok,
+            result: Some(result),
+            error: None,
+            elapsed: Duration::from_micros(20),
+        },
+        SummaryRow {
+            day: 15,
+            status: Status::TimedOut,
+            result: None,
+            error: Some("day 15: timed out after 60s".to_owned()),
+            elapsed: Duration::from_secs(60),
+        },
+    ];
+    assert_eq!(
+        summary_table(&rows),
+        "day  status          parse      part 1      part 2       total\n  \
+         1  ok            15.00µs           -    250.00ns     20.00µs\n \
+         15  timed out           -           -           -      60.00s\n"
+    );
+}
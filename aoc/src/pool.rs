@@ -0,0 +1,212 @@
+use std::{
+    any::Any,
+    collections::{BTreeMap, HashMap},
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        mpsc::{self, RecvTimeoutError},
+        Once,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+/// Recursive solutions get the stack size a main thread usually has, not a spawned thread's.
+const STACK_SIZE: usize = 8 * 1024 * 1024;
+
+const THREAD_PREFIX: &str = "aoc task day ";
+
+static QUIET_TASK_PANICS: Once = Once::new();
+
+/// Work for the pool, labelled with the day it belongs to.
+pub struct Task<T> {
+    day: u8,
+    work: Box<dyn FnOnce() -> anyhow::Result<T> + Send>,
+}
+
+impl<T> Task<T> {
+    pub fn new(day: u8, work: impl FnOnce() -> anyhow::Result<T> + Send + 'static) -> Self {
+        Task {
+            day,
+            work: Box::new(work),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Outcome<T> {
+    Done(T),
+    Failed(anyhow::Error),
+    Panicked(String),
+    /// The task's thread was left running when its time was up.
+    TimedOut,
+}
+
+#[derive(Debug)]
+pub struct Finished<T> {
+    pub day: u8,
+    pub outcome: Outcome<T>,
+    pub elapsed: Duration,
+}
+
+impl<T> Finished<T> {
+    /// Changes what the task returned, if it returned at all.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Finished<U> {
+        let outcome = match self.outcome {
+            Outcome::Done(value) => Outcome::Done(f(value)),
+            Outcome::Failed(e) => Outcome::Failed(e),
+            Outcome::Panicked(message) => Outcome::Panicked(message),
+            Outcome::TimedOut => Outcome::TimedOut,
+        };
+        Finished {
+            day: self.day,
+            outcome,
+            elapsed: self.elapsed,
+        }
+    }
+}
+
+/// Runs `tasks` on up to `jobs` threads at once, giving each `timeout` to finish. Results go
+/// to `on_finish` in the order of `tasks`, as soon as each one and all before it are done.
+///
+/// Threads cannot be stopped, so one that times out keeps running in the background; it no
+/// longer counts towards `jobs`, and whatever it returns is ignored.
+pub fn run<T: Send + 'static>(
+    tasks: Vec<Task<T>>,
+    jobs: usize,
+    timeout: Duration,
+    mut on_finish: impl FnMut(Finished<T>),
+) {
+    QUIET_TASK_PANICS.call_once(|| {
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            // Panics in tasks are reported through their outcome instead.
+            if !is_task_thread() {
+                hook(info)
+            }
+        }));
+    });
+
+    let (sender, receiver) = mpsc::channel();
+    let mut queue = tasks.into_iter().enumerate();
+    let mut running: HashMap<usize, (u8, Instant)> = HashMap::new();
+    let mut finished = BTreeMap::new();
+    let mut next = 0;
+    loop {
+        while running.len() < jobs.max(1) {
+            let Some((index, task)) = queue.next() else {
+                break;
+            };
+            let sender = sender.clone();
+            let started = Instant::now();
+            thread::Builder::new()
+                .name(format!("{}{}", THREAD_PREFIX, task.day))
+                .stack_size(STACK_SIZE)
+                .spawn(move || {
+                    let outcome = match panic::catch_unwind(AssertUnwindSafe(task.work)) {
+                        Ok(Ok(value)) => Outcome::Done(value),
+                        Ok(Err(e)) => Outcome::Failed(e),
+                        Err(payload) => Outcome::Panicked(panic_message(payload.as_ref())),
+                    };
+                    // Nobody is listening any more if the task timed out.
+                    let _ = sender.send((index, outcome, started.elapsed()));
+                })
+                .expect("spawning a task thread");
+            running.insert(index, (task.day, started));
+        }
+        let Some(deadline) = running
+            .values()
+            .map(|&(_, started)| started + timeout)
+            .min()
+        else {
+            break;
+        };
+        match receiver.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+            Ok((index, outcome, elapsed)) => {
+                if let Some((day, _)) = running.remove(&index) {
+                    finished.insert(
+                        index,
+                        Finished {
+                            day,
+                            outcome,
+                            elapsed,
+                        },
+                    );
+                }
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                let now = Instant::now();
+                running.retain(|&index, &mut (day, started)| {
+                    if now < started + timeout {
+                        return true;
+                    }
+                    let outcome = Outcome::TimedOut;
+                    finished.insert(
+                        index,
+                        Finished {
+                            day,
+                            outcome,
+                            elapsed: timeout,
+                        },
+                    );
+                    false
+                });
+            }
+            Err(RecvTimeoutError::Disconnected) => unreachable!("the pool keeps a sender"),
+        }
+        while let Some(result) = finished.remove(&next) {
+            on_finish(result);
+            next += 1;
+        }
+    }
+}
+
+fn is_task_thread() -> bool {
+    thread::current()
+        .name()
+        .is_some_and(|name| name.starts_with(THREAD_PREFIX))
+}
+
+/// The message a panic was raised with, if it had one.
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_default()
+}
+
+#[test]
+fn test_run() {
+    let tasks = vec![
+        Task::new(1, || {
+            thread::sleep(Duration::from_millis(50));
+            Ok(1)
+        }),
+        Task::new(2, || Err(anyhow::anyhow!("bad input"))),
+        Task::new(3, || panic!("oops {}", 3)),
+        Task::new(4, || loop {
+            thread::sleep(Duration::from_millis(10));
+        }),
+        Task::new(5, || Ok(5)),
+    ];
+    let mut results = Vec::new();
+    run(tasks, 2, Duration::from_millis(300), |finished| {
+        let outcome = match finished.outcome {
+            Outcome::Done(value) => format!("done {}", value),
+            Outcome::Failed(e) => format!("failed: {}", e),
+            Outcome::Panicked(message) => format!("panicked: {}", message),
+            Outcome::TimedOut => "timed out".to_owned(),
+        };
+        results.push((finished.day, outcome));
+    });
+    assert_eq!(
+        results,
+        [
+            (1, "done 1".to_owned()),
+            (2, "failed: bad input".to_owned()),
+            (3, "panicked: oops 3".to_owned()),
+            (4, "timed out".to_owned()),
+            (5, "done 5".to_owned()),
+        ]
+    );
+}
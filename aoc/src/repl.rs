@@ -0,0 +1,175 @@
+use std::io::{self, BufRead, Write};
+
+use aoc_common::{explore::Exploration, vis::Palette};
+
+use crate::days::Part;
+
+const HELP: &str = "\
+step [N]   take a step, or N of them; an empty line takes one
+show       draw the current state
+query ...  ask about the current state
+reset      go back to the start
+part 1|2   solve a part of the puzzle
+help       list these commands and the day's queries
+quit       leave";
+
+#[derive(Debug, PartialEq, Eq)]
+enum Command<'a> {
+    Step(usize),
+    Show,
+    Query(Vec<&'a str>),
+    Reset,
+    Solve(Part),
+    Help,
+    Quit,
+}
+
+impl<'a> Command<'a> {
+    fn parse(line: &'a str) -> Result<Self, String> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        match words.as_slice() {
+            [] | ["step"] => Ok(Command::Step(1)),
+            ["step", n] => match n.parse() {
+                Ok(n) if n > 0 => Ok(Command::Step(n)),
+                _ => Err(format!("expected a number of steps, not `{}`", n)),
+            },
+            ["show"] => Ok(Command::Show),
+            ["query", words @ ..] => Ok(Command::Query(words.to_vec())),
+            ["reset"] => Ok(Command::Reset),
+            ["part", "1"] => Ok(Command::Solve(Part::One)),
+            ["part", "2"] => Ok(Command::Solve(Part::Two)),
+            ["part", ..] => Err("expected `part 1` or `part 2`".to_owned()),
+            ["help"] => Ok(Command::Help),
+            ["quit" | "exit"] => Ok(Command::Quit),
+            [command, ..] => Err(format!("unknown command `{}`, try `help`", command)),
+        }
+    }
+}
+
+/// Reads commands a line at a time and answers them, like a shell.
+pub struct Repl {
+    palette: Palette,
+    commands: Box<dyn BufRead>,
+    out: Box<dyn Write>,
+}
+
+impl Repl {
+    pub fn new(commands: impl BufRead + 'static, out: impl Write + 'static) -> Self {
+        Self {
+            palette: Palette::default(),
+            commands: Box::new(commands),
+            out: Box::new(out),
+        }
+    }
+
+    pub fn palette(mut self, palette: Palette) -> Self {
+        self.palette = palette;
+        self
+    }
+
+    /// Explores what `start` returns until the commands run out or say to quit. `reset` calls
+    /// `start` again, and `part` calls `solve` for the answer and any diagnostics.
+    pub fn run<'a>(
+        &mut self,
+        day: u8,
+        start: impl Fn() -> Box<dyn Exploration + 'a>,
+        solve: impl Fn(Part) -> (Option<String>, Option<String>),
+    ) -> io::Result<()> {
+        let mut exploration = start();
+        let mut steps = 0;
+        let mut line = String::new();
+        loop {
+            write!(self.out, "day {}> ", day)?;
+            self.out.flush()?;
+            line.clear();
+            if self.commands.read_line(&mut line)? == 0 {
+                writeln!(self.out)?;
+                return Ok(());
+            }
+            let command = match Command::parse(&line) {
+                Ok(command) => command,
+                Err(e) => {
+                    writeln!(self.out, "{}", e)?;
+                    continue;
+                }
+            };
+            match command {
+                Command::Step(n) => {
+                    let mut last = None;
+                    for _ in 0..n {
+                        match exploration.step() {
+                            Some(what) => {
+                                steps += 1;
+                                last = Some(what);
+                            }
+                            None => {
+                                last = None;
+                                break;
+                            }
+                        }
+                    }
+                    match last {
+                        Some(what) => writeln!(self.out, "step {}: {}", steps, what)?,
+                        None => writeln!(self.out, "finished after {} steps", steps)?,
+                    }
+                }
+                Command::Show => writeln!(self.out, "{}", exploration.show(&self.palette))?,
+                Command::Query(words) if words.is_empty() => {
+                    write_queries(&mut self.out, exploration.as_ref())?
+                }
+                Command::Query(words) => match exploration.query(&words) {
+                    Ok(answer) => writeln!(self.out, "{}", answer)?,
+                    Err(e) => writeln!(self.out, "{}", e)?,
+                },
+                Command::Reset => {
+                    exploration = start();
+                    steps = 0;
+                    writeln!(self.out, "back to the start")?;
+                }
+                Command::Solve(part) => {
+                    let (answer, diagnostics) = solve(part);
+                    writeln!(
+                        self.out,
+                        "part {}: {}",
+                        part.number(),
+                        answer.as_deref().unwrap_or("no answer")
+                    )?;
+                    if let Some(diagnostics) = diagnostics {
+                        writeln!(self.out, "{}", diagnostics)?;
+                    }
+                }
+                Command::Help => {
+                    writeln!(self.out, "{}", HELP)?;
+                    write_queries(&mut self.out, exploration.as_ref())?;
+                }
+                Command::Quit => return Ok(()),
+            }
+        }
+    }
+}
+
+fn write_queries(out: &mut dyn Write, exploration: &dyn Exploration) -> io::Result<()> {
+    writeln!(out, "queries:")?;
+    for (query, answers) in exploration.queries() {
+        writeln!(out, "  {:<16} {}", query, answers)?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_parse_command() {
+    assert_eq!(Command::parse("\n"), Ok(Command::Step(1)));
+    assert_eq!(Command::parse("step 20"), Ok(Command::Step(20)));
+    assert!(Command::parse("step 0").is_err());
+    assert_eq!(
+        Command::parse(" query at 500,8 "),
+        Ok(Command::Query(vec!["at", "500,8"]))
+    );
+    assert_eq!(Command::parse("part 2"), Ok(Command::Solve(Part::Two)));
+    assert!(Command::parse("part 3").is_err());
+    assert_eq!(Command::parse("exit"), Ok(Command::Quit));
+    assert_eq!(
+        Command::parse("jump").unwrap_err(),
+        "unknown command `jump`, try `help`"
+    );
+}
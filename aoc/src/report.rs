@@ -0,0 +1,294 @@
+use std::{cell::RefCell, collections::HashMap, fmt::Write, rc::Rc, time::Duration};
+
+use aoc_common::{
+    vis::{escape_html, Frame, Palette, Visualize},
+    Params, ParseError, Solution,
+};
+use day10::{Day10, Screen};
+use day7::{directory_sizes, Day7, Directory};
+
+use crate::output::SummaryRow;
+
+/// A picture of how a day's puzzle came out, as a fragment of HTML.
+#[derive(Debug)]
+pub struct Figure {
+    pub title: &'static str,
+    pub html: String,
+}
+
+/// The figure for the days that have one.
+pub fn figure(day: u8, input: &str, params: &Params) -> Result<Option<Figure>, ParseError> {
+    let (title, html) = match day {
+        7 => ("Directory tree", directory_tree(&Day7::parse(input)?)),
+        10 => ("CRT", crt(&Day10::part_2(&Day10::parse(input)?, params))),
+        12 => ("Part 1 path", last_frame::<day12::Day12>(input, params)?),
+        14 => (
+            "Cave after part 1",
+            last_frame::<day14::Day14>(input, params)?,
+        ),
+        _ => return Ok(None),
+    };
+    Ok(Some(Figure { title, html }))
+}
+
+fn directory_tree(top_dir: &Rc<RefCell<Directory>>) -> String {
+    enum Step {
+        Open(Rc<RefCell<Directory>>),
+        Close(Rc<RefCell<Directory>>),
+    }
+    let sizes: HashMap<*const RefCell<Directory>, usize> = directory_sizes(top_dir)
+        .into_iter()
+        .map(|(directory, size)| (Rc::as_ptr(&directory), size))
+        .collect();
+    // Nested directories go on an explicit stack, so a deep tree cannot overflow the real one.
+    let mut html = String::new();
+    let mut stack = vec![Step::Open(Rc::clone(top_dir))];
+    while let Some(step) = stack.pop() {
+        match step {
+            Step::Open(directory) => {
+                let top = Rc::ptr_eq(&directory, top_dir);
+                let _ = write!(
+                    html,
+                    "{}<details{}><summary>{} <small>{}</small></summary><ul>",
+                    if top { "" } else { "<li>" },
+                    if top { " open" } else { "" },
+                    escape_html(directory.borrow().name()),
+                    sizes[&Rc::as_ptr(&directory)]
+                );
+                stack.push(Step::Close(Rc::clone(&directory)));
+                for subdirectory in directory.borrow().subdirectories().iter().rev() {
+                    stack.push(Step::Open(Rc::clone(subdirectory)));
+                }
+            }
+            Step::Close(directory) => {
+                for (name, size) in directory.borrow().files() {
+                    let _ = write!(
+                        html,
+                        "<li>{} <small>{}</small></li>",
+                        escape_html(name),
+                        size
+                    );
+                }
+                html.push_str("</ul></details>");
+                if !Rc::ptr_eq(&directory, top_dir) {
+                    html.push_str("</li>");
+                }
+            }
+        }
+    }
+    html
+}
+
+/// Draws the screen one square per pixel.
+fn crt(screen: &Screen) -> String {
+    let width = screen.0.iter().map(|row| row.len()).max().unwrap_or(0);
+    let mut html = format!(
+        "<div class=\"crt\" style=\"grid-template-columns: repeat({}, 8px)\">",
+        width
+    );
+    for row in &screen.0 {
+        for col in 0..width {
+            html.push_str(match row.get(col) {
+                Some('#') => "<i class=\"lit\"></i>",
+                _ => "<i></i>",
+            });
+        }
+    }
+    html.push_str("</div>");
+    html
+}
+
+/// The day's animation as it ends.
+fn last_frame<S: Visualize>(input: &str, params: &Params) -> Result<String, ParseError> {
+    let parsed = S::parse(input)?;
+    let frame = S::frames(&parsed, params)
+        .last()
+        .map(|frame| frame.render(&Palette::html()))
+        .unwrap_or_default();
+    Ok(format!("<pre class=\"grid\">{}</pre>", frame))
+}
+
+const STYLE: &str = "
+body { background: #0f0f23; color: #cccccc; font-family: monospace; margin: 2em; }
+h1, h2 { color: #00cc00; font-weight: normal; }
+table { border-collapse: collapse; }
+th, td { padding: 0.2em 0.8em; text-align: left; vertical-align: top; border-bottom: 1px solid #333340; }
+td.time { text-align: right; }
+pre { margin: 0; }
+small { color: #888888; }
+.ok { color: #00cc00; }
+.no-answer { color: #ffff66; }
+.failed, .panicked, .timed-out, .error { color: #ff6666; }
+pre.grid { font-size: 10px; line-height: 1; }
+.empty { color: #333340; }
+.wall { color: #cccccc; }
+.trail { color: #009900; }
+.active { color: #ffff66; }
+.highlight { color: #ff6666; }
+.crt { display: grid; gap: 1px; }
+.crt i { width: 8px; height: 8px; background: #1f1f3f; }
+.crt i.lit { background: #ffff66; }
+details ul { list-style: none; margin: 0; padding-left: 1.5em; }
+";
+
+/// A self-contained page with every day's answers and timings, followed by its figures.
+pub fn page(days: &[(SummaryRow, Option<Figure>)], elapsed: Duration) -> String {
+    let mut html = format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>Advent of Code 2022</title>\n<style>{}</style>\n</head>\n<body>\n\
+         <h1>Advent of Code 2022</h1>\n",
+        STYLE
+    );
+    let finished = days.iter().filter(|(row, _)| row.finished()).count();
+    let _ = writeln!(
+        html,
+        "<p>{} of {} days finished in {:.2?}.</p>",
+        finished,
+        days.len(),
+        elapsed
+    );
+    html.push_str(
+        "<table>\n<tr><th>Day</th><th>Status</th><th>Part 1</th><th>Part 2</th>\
+         <th>Parse</th><th>Part 1 time</th><th>Part 2 time</th><th>Total</th></tr>\n",
+    );
+    for (row, _) in days {
+        html.push_str(&table_row(row));
+    }
+    html.push_str("</table>\n");
+    for (row, figure) in days {
+        if let Some(figure) = figure {
+            let _ = writeln!(
+                html,
+                "<section id=\"day{}\">\n<h2>Day {}: {}</h2>\n{}\n</section>",
+                row.day,
+                row.day,
+                escape_html(figure.title),
+                figure.html
+            );
+        }
+    }
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+fn table_row(row: &SummaryRow) -> String {
+    let status = row.status.name();
+    let mut html = format!(
+        "<tr><td>{}</td><td class=\"{}\">{}</td>",
+        row.day,
+        status.replace(' ', "-"),
+        status
+    );
+    let time = |duration: Option<Duration>| {
+        let time = duration.map_or_else(|| "-".to_owned(), |d| format!("{:.2?}", d));
+        format!("<td class=\"time\">{}</td>", escape_html(&time))
+    };
+    match &row.result {
+        Some(result) => {
+            let part = |number: u8| {
+                result
+                    .parts
+                    .iter()
+                    .find(|part| part.part.number() == number)
+            };
+            for number in [1, 2] {
+                let answer = part(number).and_then(|part| part.answer.as_deref());
+                html.push_str(&match answer {
+                    Some(answer) if answer.contains('\n') => {
+                        format!("<td><pre>{}</pre></td>", escape_html(answer))
+                    }
+                    Some(answer) => format!("<td>{}</td>", escape_html(answer)),
+                    None => "<td class=\"no-answer\">no answer</td>".to_owned(),
+                });
+            }
+            html.push_str(&time(Some(result.parse_elapsed)));
+            for number in [1, 2] {
+                html.push_str(&time(part(number).map(|part| part.elapsed)));
+            }
+        }
+        None => {
+            let error = row.error.as_deref().unwrap_or_default();
+            let _ = write!(
+                html,
+                "<td colspan=\"2\" class=\"error\"><pre>{}</pre></td>",
+                escape_html(error)
+            );
+            html.push_str(&time(None).repeat(3));
+        }
+    }
+    html.push_str(&time(Some(row.elapsed)));
+    html.push_str("</tr>\n");
+    html
+}
+
+#[test]
+fn test_figures() {
+    let html = |day: u8, input: &str| {
+        let params = crate::days::find(day).unwrap().params();
+        figure(day, input, &params).unwrap().unwrap().html
+    };
+    let tree = html(7, include_str!("../../day7/example.txt"));
+    assert!(tree.starts_with("<details open><summary>/ <small>48381165</small></summary>"));
+    assert!(tree.contains(
+        "<details><summary>e <small>584</small></summary><ul><li>i <small>584</small></li></ul></details>"
+    ));
+
+    let screen = html(10, include_str!("../../day10/example.txt"));
+    assert!(screen.contains("repeat(40, 8px)"));
+    assert_eq!(screen.matches("<i").count(), 240);
+
+    let cave = html(14, include_str!("../../day14/example.txt"));
+    assert_eq!(cave.matches("<span class=\"active\">o</span>").count(), 24);
+
+    assert!(figure(1, "", &Params::defaults(&[])).unwrap().is_none());
+}
+
+#[test]
+fn test_page() {
+    use crate::{
+        days::{DayResult, Part, PartResult},
+        output::Status,
+    };
+
+    let result = DayResult {
+        day: 1,
+        parse_elapsed: Duration::from_micros(3),
+        parts: vec![PartResult {
+            part: Part::One,
+            answer: Some("24000".to_owned()),
+            diagnostics: None,
+            elapsed: Duration::from_micros(1),
+        }],
+    };
+    let days = [
+        (
+            SummaryRow {
+                day: 1,
+                status: Status::Ok,
+                result: Some(result),
+                error: None,
+                elapsed: Duration::from_micros(5),
+            },
+            None,
+        ),
+        (
+            SummaryRow {
+                day: 2,
+                status: Status::Failed,
+                result: None,
+                error: Some("day 2: expected <shape>".to_owned()),
+                elapsed: Duration::from_micros(5),
+            },
+            Some(Figure {
+                title: "Guide",
+                html: "<p>rock</p>".to_owned(),
+            }),
+        ),
+    ];
+    let html = page(&days, Duration::from_millis(2));
+    assert!(html.contains("<p>1 of 2 days finished in 2.00ms.</p>"));
+    assert!(html.contains("<td>24000</td><td class=\"no-answer\">no answer</td>"));
+    assert!(html.contains("<pre>day 2: expected &lt;shape&gt;</pre>"));
+    assert!(html.contains("<section id=\"day2\">\n<h2>Day 2: Guide</h2>\n<p>rock</p>\n</section>"));
+    assert!(!html.contains("http"));
+}
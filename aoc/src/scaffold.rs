@@ -0,0 +1,160 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+
+/// The files of a new day, relative to the workspace root. `{n}` stands for the day number.
+const FILES: [(&str, &str); 7] = [
+    (
+        "day{n}/Cargo.toml",
+        include_str!("../templates/Cargo.toml.in"),
+    ),
+    ("day{n}/src/lib.rs", include_str!("../templates/lib.rs.in")),
+    (
+        "day{n}/src/main.rs",
+        include_str!("../templates/main.rs.in"),
+    ),
+    ("day{n}/src/gen.rs", include_str!("../templates/gen.rs.in")),
+    ("day{n}/example.txt", ""),
+    ("day{n}/input.txt", ""),
+    (
+        "fuzz/fuzz_targets/day{n}.rs",
+        include_str!("../templates/fuzz_target.rs.in"),
+    ),
+];
+
+/// The line each of these files has per day, kept in day order.
+const REGISTRIES: [(&str, &str); 4] = [
+    ("Cargo.toml", "    \"day{n}\","),
+    ("aoc/Cargo.toml", "day{n} = { path = \"../day{n}\" }"),
+    ("aoc/src/days.rs", "        solver::<day{n}::Day{n}>(),"),
+    ("fuzz/Cargo.toml", "day{n} = { path = \"../day{n}\" }"),
+];
+
+const FUZZ_BIN: &str = "
+[[bin]]
+name = \"day{n}\"
+path = \"fuzz_targets/day{n}.rs\"
+test = false
+doc = false
+bench = false
+";
+
+#[derive(Debug, Default)]
+pub struct Scaffolded {
+    pub created: Vec<PathBuf>,
+    pub updated: Vec<PathBuf>,
+}
+
+fn fill(template: &str, day: u8) -> String {
+    template.replace("{n}", &day.to_string())
+}
+
+/// Adds `day`'s line to `text`, just before the first day after it or else after the last.
+fn register(text: &str, line: &str, day: u8) -> Result<String, String> {
+    let mut lines: Vec<String> = text.lines().map(str::to_owned).collect();
+    let listed: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, text)| (1..=25).find(|&d| *text == fill(line, d)).map(|d| (i, d)))
+        .collect();
+    if listed.iter().any(|&(_, d)| d == day) {
+        return Err(format!("already lists day {}", day));
+    }
+    let at = match listed.iter().find(|&&(_, d)| d > day) {
+        Some(&(i, _)) => i,
+        None => match listed.last() {
+            Some(&(i, _)) => i + 1,
+            None => return Err("lists no days to add to".to_owned()),
+        },
+    };
+    lines.insert(at, fill(line, day));
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Creates the crate for `day` under `root`, the workspace root, and lists it everywhere the
+/// other days are. Nothing is written unless every file can be updated.
+pub fn create(root: &Path, day: u8) -> Result<Scaffolded> {
+    let directory = root.join(format!("day{}", day));
+    if directory.exists() {
+        bail!("{} already exists", directory.display());
+    }
+    let mut edits = Vec::new();
+    for (file, line) in REGISTRIES {
+        let path = root.join(file);
+        let text =
+            fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))?;
+        let mut text =
+            register(&text, line, day).map_err(|e| anyhow!("{}: {}", path.display(), e))?;
+        if file == "fuzz/Cargo.toml" {
+            text.push_str(&fill(FUZZ_BIN, day));
+        }
+        edits.push((path, text));
+    }
+
+    let mut scaffolded = Scaffolded::default();
+    for (file, template) in FILES {
+        let path = root.join(fill(file, day));
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).with_context(|| format!("creating {}", parent.display()))?;
+        }
+        fs::write(&path, fill(template, day))
+            .with_context(|| format!("writing {}", path.display()))?;
+        scaffolded.created.push(path);
+    }
+    for (path, text) in edits {
+        fs::write(&path, text).with_context(|| format!("writing {}", path.display()))?;
+        scaffolded.updated.push(path);
+    }
+    Ok(scaffolded)
+}
+
+#[test]
+fn test_register() {
+    let line = "day{n} = { path = \"../day{n}\" }";
+    let text = "[dependencies]\nday1 = { path = \"../day1\" }\nday3 = { path = \"../day3\" }\n";
+    assert_eq!(
+        register(text, line, 2).unwrap(),
+        "[dependencies]\nday1 = { path = \"../day1\" }\nday2 = { path = \"../day2\" }\nday3 = { path = \"../day3\" }\n"
+    );
+    assert!(register(text, line, 4)
+        .unwrap()
+        .ends_with("day3 = { path = \"../day3\" }\nday4 = { path = \"../day4\" }\n"));
+    assert_eq!(register(text, line, 3).unwrap_err(), "already lists day 3");
+    assert_eq!(
+        register("[dependencies]\n", line, 1).unwrap_err(),
+        "lists no days to add to"
+    );
+}
+
+#[test]
+fn test_create() {
+    let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    for (file, _) in REGISTRIES {
+        let path = root.join(file);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::copy(Path::new("..").join(file), path).unwrap();
+    }
+
+    let scaffolded = create(&root, 16).unwrap();
+    assert_eq!(scaffolded.created.len(), FILES.len());
+    assert_eq!(scaffolded.updated.len(), REGISTRIES.len());
+    let read = |file: &str| fs::read_to_string(root.join(file)).unwrap();
+    assert!(read("day16/src/lib.rs").contains("impl Solution for Day16 {\n    const DAY: u8 = 16;"));
+    assert_eq!(read("day16/input.txt"), "");
+    assert!(read("Cargo.toml").contains("    \"day15\",\n    \"day16\",\n]"));
+    assert!(read("aoc/src/days.rs")
+        .contains("        solver::<day15::Day15>(),\n        solver::<day16::Day16>(),\n    ]"));
+    assert!(read("fuzz/Cargo.toml")
+        .ends_with("path = \"fuzz_targets/day16.rs\"\ntest = false\ndoc = false\nbench = false\n"));
+
+    assert!(create(&root, 16).is_err());
+    fs::remove_dir_all(root.join("day16")).unwrap();
+    // Registering fails before anything is written.
+    assert!(create(&root, 16).is_err());
+    assert!(!root.join("day16").exists());
+    fs::remove_dir_all(root).unwrap();
+}
@@ -0,0 +1,385 @@
+use std::{fs, io, path::Path};
+
+use anyhow::{bail, Context, Result};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::fetch::{self, Config, YEAR};
+
+/// How long to hold off after a wrong answer when the site doesn't say.
+const DEFAULT_COOLDOWN: u64 = 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// The site refused to look at the answer because the last one was too recent.
+    TooSoon,
+    /// The part is already solved, or part 1 isn't yet.
+    WrongLevel,
+}
+
+impl Verdict {
+    pub fn describe(self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "wrong, too high",
+            Verdict::TooLow => "wrong, too low",
+            Verdict::Wrong => "wrong",
+            Verdict::TooSoon => "not checked, answered too recently",
+            Verdict::WrongLevel => "not checked, this part is already solved or not unlocked",
+        }
+    }
+
+    fn is_wrong(self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the Unix epoch, like `wait_until`.
+    pub submitted_at: u64,
+    /// When the site will take another answer, if it asked us to wait.
+    pub wait_until: Option<u64>,
+}
+
+/// Every answer submitted for one day, kept in the fetch cache as `submissions.json`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct History {
+    pub attempts: Vec<Attempt>,
+}
+
+/// When the site will take another answer from this account, kept in the fetch cache as
+/// `cooldown.json`.
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cooldown {
+    /// Seconds since the Unix epoch.
+    pub wait_until: Option<u64>,
+}
+
+impl Cooldown {
+    pub fn load(path: &Path) -> Result<Self> {
+        load_json(path)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        save_json(self, path)
+    }
+
+    /// Fails if the site wants us to wait.
+    pub fn check(&self, now: u64) -> Result<()> {
+        match self.wait_until {
+            Some(wait_until) if wait_until > now => {
+                bail!("the site asked to wait {}s more", wait_until - now)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+fn load_json<T: DeserializeOwned + Default>(path: &Path) -> Result<T> {
+    match fs::read_to_string(path) {
+        Ok(text) => {
+            serde_json::from_str(&text).with_context(|| format!("parsing {}", path.display()))
+        }
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(T::default()),
+        Err(e) => Err(e).with_context(|| format!("reading {}", path.display())),
+    }
+}
+
+fn save_json(value: &impl Serialize, path: &Path) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).with_context(|| format!("creating {}", dir.display()))?;
+    }
+    let json = serde_json::to_string_pretty(value)?;
+    fs::write(path, json + "\n").with_context(|| format!("writing {}", path.display()))
+}
+
+impl History {
+    pub fn load(path: &Path) -> Result<Self> {
+        load_json(path)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        save_json(self, path)
+    }
+
+    pub fn accepted(&self, part: u8) -> Option<&Attempt> {
+        self.attempts
+            .iter()
+            .find(|attempt| attempt.part == part && attempt.verdict == Verdict::Correct)
+    }
+
+    /// Fails if the history already shows `answer` is wrong.
+    pub fn check(&self, part: u8, answer: &str) -> Result<()> {
+        if let Some(accepted) = self.accepted(part) {
+            bail!("part {} was already solved with {}", part, accepted.answer);
+        }
+        let number = answer.parse::<i64>().ok();
+        for attempt in self.attempts.iter().filter(|a| a.part == part) {
+            if attempt.verdict.is_wrong() && attempt.answer == answer {
+                bail!(
+                    "{} was already rejected as {}",
+                    answer,
+                    attempt.verdict.describe()
+                );
+            }
+            let (Some(number), Ok(bound)) = (number, attempt.answer.parse::<i64>()) else {
+                continue;
+            };
+            match attempt.verdict {
+                Verdict::TooHigh if number >= bound => {
+                    bail!("{} can't be right, {} was already too high", answer, bound)
+                }
+                Verdict::TooLow if number <= bound => {
+                    bail!("{} can't be right, {} was already too low", answer, bound)
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+pub enum Submitted {
+    AlreadyAccepted,
+    Sent(Attempt),
+}
+
+/// Posts `answer` unless the day's history or the account's cooldown rules it out, recording
+/// whatever the site says.
+pub fn submit(config: &Config, day: u8, part: u8, answer: &str, now: u64) -> Result<Submitted> {
+    let cache = config.cache();
+    let path = cache.submissions_path(day);
+    let mut history = History::load(&path)?;
+    if history
+        .accepted(part)
+        .is_some_and(|accepted| accepted.answer == answer)
+    {
+        return Ok(Submitted::AlreadyAccepted);
+    }
+    let cooldown_path = cache.cooldown_path();
+    let cooldown = Cooldown::load(&cooldown_path)?;
+    history
+        .check(part, answer)
+        .and_then(|()| cooldown.check(now))
+        .with_context(|| format!("not submitting {}", answer))?;
+
+    let url = format!("{}/{}/day/{}/answer", config.base_url(), YEAR, day);
+    let response = fetch::agent()
+        .post(&url)
+        .set("Cookie", &format!("session={}", config.session()?))
+        .send_form(&[("level", &part.to_string()), ("answer", answer)])
+        .with_context(|| format!("posting to {}", url))?;
+    let page = response
+        .into_string()
+        .with_context(|| format!("reading the response from {}", url))?;
+    let text = article_text(&page);
+    let Some(verdict) = parse_verdict(&text) else {
+        bail!("unexpected response from {}: {}", url, text.trim());
+    };
+    let wait = parse_wait(&text).or(verdict.is_wrong().then_some(DEFAULT_COOLDOWN));
+    let attempt = Attempt {
+        part,
+        answer: answer.to_owned(),
+        verdict,
+        submitted_at: now,
+        wait_until: wait.map(|wait| now + wait),
+    };
+    history.attempts.push(attempt.clone());
+    history.save(&path)?;
+    if attempt.wait_until.is_some() {
+        let cooldown = Cooldown {
+            wait_until: attempt.wait_until,
+        };
+        cooldown.save(&cooldown_path)?;
+    }
+    Ok(Submitted::Sent(attempt))
+}
+
+/// The text of the page's `<article>`, which holds the verdict, without any tags.
+fn article_text(page: &str) -> String {
+    let article = page
+        .split_once("<article>")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(page, |(article, _)| article);
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+fn parse_verdict(text: &str) -> Option<Verdict> {
+    Some(if text.contains("That's the right answer") {
+        Verdict::Correct
+    } else if text.contains("That's not the right answer") {
+        if text.contains("too high") {
+            Verdict::TooHigh
+        } else if text.contains("too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        }
+    } else if text.contains("You gave an answer too recently") {
+        Verdict::TooSoon
+    } else if text.contains("You don't seem to be solving the right level") {
+        Verdict::WrongLevel
+    } else {
+        return None;
+    })
+}
+
+/// How many seconds the site asked us to wait, from "You have 1m 5s left to wait" or
+/// "please wait 5 minutes before trying again".
+fn parse_wait(text: &str) -> Option<u64> {
+    if let Some((before, _)) = text.split_once(" left to wait") {
+        let (_, amount) = before.rsplit_once("You have ")?;
+        return amount.split_whitespace().try_fold(0, |total, part| {
+            let (number, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+            let number: u64 = number.parse().ok()?;
+            let scale = match unit {
+                "h" => 3600,
+                "m" => 60,
+                "s" => 1,
+                _ => return None,
+            };
+            Some(total + number * scale)
+        });
+    }
+    let lower = text.to_lowercase();
+    let (_, after) = lower.split_once("please wait ")?;
+    let mut words = after.split_whitespace();
+    let number = match words.next()? {
+        "one" => 1,
+        number => number.parse().ok()?,
+    };
+    words.next()?.starts_with("minute").then_some(number * 60)
+}
+
+#[test]
+fn test_parse_response() {
+    let page = "<main><article><p>That's not the right answer; your answer is too high. \
+        If you're stuck, make sure you're using the full input data. \
+        Please wait one minute before trying again. <a href=\"/2022/day/1\">[Return to Day 1]</a>\
+        </p></article></main>";
+    let text = article_text(page);
+    assert!(!text.contains('<'));
+    assert_eq!(parse_verdict(&text), Some(Verdict::TooHigh));
+    assert_eq!(parse_wait(&text), Some(60));
+
+    let text = "You gave an answer too recently; you have to wait after submitting an answer \
+        before trying again.  You have 1m 5s left to wait.";
+    assert_eq!(parse_verdict(text), Some(Verdict::TooSoon));
+    assert_eq!(parse_wait(text), Some(65));
+
+    let text = "That's the right answer! You are one gold star closer to saving your vacation.";
+    assert_eq!(parse_verdict(text), Some(Verdict::Correct));
+    assert_eq!(parse_wait(text), None);
+    assert_eq!(parse_verdict("Please log in."), None);
+}
+
+#[test]
+fn test_history_check() {
+    let attempt = |answer: &str, verdict, wait_until| Attempt {
+        part: 1,
+        answer: answer.to_owned(),
+        verdict,
+        submitted_at: 100,
+        wait_until,
+    };
+    let mut history = History {
+        attempts: vec![
+            attempt("500", Verdict::TooHigh, Some(160)),
+            attempt("100", Verdict::TooLow, None),
+            attempt("abc", Verdict::Wrong, None),
+        ],
+    };
+    assert!(history.check(1, "300").is_ok());
+    assert!(history.check(1, "500").is_err());
+    assert!(history.check(1, "501").is_err());
+    assert!(history.check(1, "100").is_err());
+    assert!(history.check(1, "abc").is_err());
+    assert!(history.check(2, "500").is_ok());
+    history
+        .attempts
+        .push(attempt("300", Verdict::Correct, None));
+    assert!(history.check(1, "301").is_err());
+
+    let cooldown = Cooldown {
+        wait_until: Some(160),
+    };
+    assert!(cooldown.check(150).is_err());
+    assert!(cooldown.check(160).is_ok());
+    assert!(Cooldown::default().check(0).is_ok());
+}
+
+#[test]
+fn test_submit_against_mock() {
+    let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", server.server_addr().to_ip().unwrap());
+    let handle = std::thread::spawn(move || {
+        let mut bodies = Vec::new();
+        for mut request in server.incoming_requests().take(2) {
+            let mut body = String::new();
+            request.as_reader().read_to_string(&mut body).unwrap();
+            let text = match body.as_str() {
+                "level=1&answer=24000" => "<article><p>That's the right answer!</p></article>",
+                _ => {
+                    "<article><p>That's not the right answer; your answer is too low. \
+                    Please wait 5 minutes before trying again.</p></article>"
+                }
+            };
+            bodies.push(body);
+            request
+                .respond(tiny_http::Response::from_string(text))
+                .unwrap();
+        }
+        bodies
+    });
+    let dir = std::env::temp_dir().join(format!("aoc-submit-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    let config = Config {
+        session: Some("secret".to_owned()),
+        base_url: Some(base_url),
+        cache_dir: Some(dir.clone()),
+    };
+    let sent = |answer, now| match submit(&config, 1, 1, answer, now) {
+        Ok(Submitted::Sent(attempt)) => Ok((attempt.verdict, attempt.wait_until)),
+        Ok(Submitted::AlreadyAccepted) => Err("already accepted".to_owned()),
+        Err(e) => Err(e.to_string()),
+    };
+    assert_eq!(sent("1000", 0), Ok((Verdict::TooLow, Some(300))));
+    assert!(sent("2000", 10).is_err());
+    // The cooldown is for the account, so another day has to wait too.
+    let error = match submit(&config, 2, 1, "7", 10) {
+        Err(e) => format!("{:#}", e),
+        Ok(_) => "sent".to_owned(),
+    };
+    assert!(error.contains("wait 290s more"), "{}", error);
+    assert!(sent("999", 400).is_err());
+    assert_eq!(sent("24000", 400), Ok((Verdict::Correct, None)));
+    assert!(matches!(
+        submit(&config, 1, 1, "24000", 500),
+        Ok(Submitted::AlreadyAccepted)
+    ));
+    assert_eq!(
+        handle.join().unwrap(),
+        ["level=1&answer=1000", "level=1&answer=24000"]
+    );
+    let history = History::load(&config.cache().submissions_path(1)).unwrap();
+    assert_eq!(history.attempts.len(), 2);
+    fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,264 @@
+use std::{
+    fs, io,
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    time::Duration,
+};
+
+use anyhow::{Context, Result};
+use aoc_common::{InputSource, Params, ParamsFile};
+use serde::Deserialize;
+
+use crate::{
+    days::{self, Day, Part},
+    pool,
+};
+
+/// Known answers for one day, read from `dayN/answers.toml`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Answers {
+    pub example: Option<Expected>,
+    pub input: Option<Expected>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Expected {
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+impl Expected {
+    fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part_1.as_deref(),
+            Part::Two => self.part_2.as_deref(),
+        }
+    }
+}
+
+impl Answers {
+    /// Returns `None` when the day has no answers file yet.
+    pub fn load(path: &Path) -> Result<Option<Self>> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e).with_context(|| format!("reading {}", path.display())),
+        };
+        toml::from_str(&text)
+            .map(Some)
+            .with_context(|| format!("parsing {}", path.display()))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Source {
+    Example,
+    Input,
+}
+
+impl Source {
+    fn name(&self) -> &'static str {
+        match self {
+            Source::Example => "example",
+            Source::Input => "input",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Mismatch {
+        expected: String,
+        actual: String,
+    },
+    /// The input could not be read or parsed, or the solution panicked.
+    Fail(String),
+}
+
+#[derive(Debug)]
+pub struct Check {
+    pub day: u8,
+    pub source: Source,
+    pub part: Part,
+    pub outcome: Outcome,
+    pub elapsed: Duration,
+}
+
+/// Checks every known answer for `day` against its bundled example and input files.
+pub fn verify_day(day: &dyn Day, file: &ParamsFile) -> Result<Option<Vec<Check>>> {
+    let directory = days::directory(day.number());
+    let Some(answers) = Answers::load(&directory.join("answers.toml"))? else {
+        return Ok(None);
+    };
+    let mut checks = Vec::new();
+    for (source, expected) in [
+        (Source::Example, &answers.example),
+        (Source::Input, &answers.input),
+    ] {
+        let Some(expected) = expected else {
+            continue;
+        };
+        let mut params = day.params();
+        file.apply(day.number(), source == Source::Example, &mut params)
+            .map_err(anyhow::Error::msg)?;
+        let input = InputSource::Path(directory.join(format!("{}.txt", source.name())))
+            .read(day.number())
+            .map_err(|e| e.to_string());
+        checks.extend(check(day, source, input.as_deref(), expected, &params));
+    }
+    Ok(Some(checks))
+}
+
+pub fn check(
+    day: &dyn Day,
+    source: Source,
+    input: Result<&str, &String>,
+    expected: &Expected,
+    params: &Params,
+) -> Vec<Check> {
+    let parts: Vec<Part> = [Part::One, Part::Two]
+        .into_iter()
+        .filter(|&part| expected.get(part).is_some())
+        .collect();
+    let failed = |message: String| {
+        parts
+            .iter()
+            .map(|&part| Check {
+                day: day.number(),
+                source,
+                part,
+                outcome: Outcome::Fail(message.clone()),
+                elapsed: Duration::ZERO,
+            })
+            .collect()
+    };
+    let input = match input {
+        Ok(input) => input,
+        Err(message) => return failed(message.clone()),
+    };
+
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let result = panic::catch_unwind(AssertUnwindSafe(|| day.run(input, &parts, params)));
+    panic::set_hook(hook);
+
+    let result = match result {
+        Ok(Ok(result)) => result,
+        Ok(Err(e)) => return failed(e.diagnostic(input)),
+        Err(payload) => {
+            let message = pool::panic_message(payload.as_ref());
+            return failed(format!("panicked: {}", message));
+        }
+    };
+    result
+        .parts
+        .into_iter()
+        .map(|part| {
+            let expected = expected.get(part.part).unwrap_or_default();
+            let outcome = match part.answer {
+                Some(actual) if actual == expected => Outcome::Pass,
+                actual => Outcome::Mismatch {
+                    expected: expected.to_owned(),
+                    actual: actual.unwrap_or_else(|| "no answer".to_owned()),
+                },
+            };
+            Check {
+                day: day.number(),
+                source,
+                part: part.part,
+                outcome,
+                elapsed: part.elapsed,
+            }
+        })
+        .collect()
+}
+
+pub fn print_check(check: &Check) {
+    let label = format!(
+        "day {:>2} {:<7} part {}",
+        check.day,
+        check.source.name(),
+        check.part.number()
+    );
+    match &check.outcome {
+        Outcome::Pass => println!("{}: ok ({:?})", label, check.elapsed),
+        Outcome::Mismatch { expected, actual } => {
+            println!("{}: MISMATCH ({:?})", label, check.elapsed);
+            print_indented("expected", expected);
+            print_indented("actual", actual);
+        }
+        Outcome::Fail(message) => {
+            println!("{}: FAIL", label);
+            print_indented("error", message);
+        }
+    }
+}
+
+fn print_indented(heading: &str, text: &str) {
+    if text.contains('\n') {
+        println!("    {}:", heading);
+        for line in text.lines() {
+            println!("      {}", line);
+        }
+    } else {
+        println!("    {}: {}", heading, text);
+    }
+}
+
+#[test]
+fn test_check_example() {
+    let day = days::find(7).unwrap();
+    let input = include_str!("../../day7/example.txt");
+    let expected = Expected {
+        part_1: Some("95437".to_owned()),
+        part_2: Some("1".to_owned()),
+    };
+    let checks = check(
+        day.as_ref(),
+        Source::Example,
+        Ok(input),
+        &expected,
+        &day.params(),
+    );
+    assert_eq!(checks.len(), 2);
+    assert_eq!(checks[0].outcome, Outcome::Pass);
+    assert_eq!(
+        checks[1].outcome,
+        Outcome::Mismatch {
+            expected: "1".to_owned(),
+            actual: "24933642".to_owned()
+        }
+    );
+}
+
+#[test]
+fn test_check_parse_failure() {
+    let day = days::find(4).unwrap();
+    let expected = Expected {
+        part_1: Some("2".to_owned()),
+        part_2: None,
+    };
+    let checks = check(
+        day.as_ref(),
+        Source::Input,
+        Ok("1-2,x-4\n"),
+        &expected,
+        &day.params(),
+    );
+    assert_eq!(checks.len(), 1);
+    assert!(matches!(
+        &checks[0].outcome,
+        Outcome::Fail(message) if message.starts_with("line 1, column 5: invalid number `x`")
+    ));
+}
+
+#[test]
+fn test_answers_file() {
+    let answers: Answers = toml::from_str(include_str!("../../day10/answers.toml")).unwrap();
+    let expected = answers.input.unwrap();
+    assert_eq!(expected.part_1.as_deref(), Some("14860"));
+    assert_eq!(expected.part_2.as_deref(), Some("RGZEHURK"));
+}
@@ -0,0 +1,19 @@
+use aoc_common::{Generate, Rng};
+
+use crate::Day1;
+
+/// `size` is the number of elves.
+impl Generate for Day1 {
+    const DEFAULT_SIZE: usize = 250;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let elves: Vec<String> = (0..size)
+            .map(|_| {
+                (0..rng.range(1..=15))
+                    .map(|_| format!("{}\n", rng.range(1000..=60000)))
+                    .collect()
+            })
+            .collect();
+        elves.join("\n")
+    }
+}
@@ -0,0 +1,205 @@
+//! Day 1: Calorie Counting. Each elf's snacks are a block of numbers, and the
+//! answers are the largest totals carried.
+
+pub mod gen;
+pub mod stats;
+pub mod stream;
+
+use aoc_common::{lines, parse::Line, Param, Params, ParseError, Solution};
+
+use std::str::FromStr;
+
+use crate::stats::{Stats, TopK};
+
+/// One elf's snacks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elf {
+    /// Where the elf comes in the list, numbered from zero.
+    pub index: usize,
+    /// The calories in each snack.
+    pub items: Vec<i32>,
+}
+
+/// How `parse_input_with` treats whitespace around the numbers. Lines may end in `\r\n`
+/// either way.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Whitespace {
+    /// A line is a number and nothing else, or empty.
+    #[default]
+    Strict,
+    /// Spaces, tabs and stray `\r`s around a number are ignored, and a line with only those
+    /// on it separates elves like an empty one.
+    Trim,
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    type Input = Vec<Elf>;
+    type Output1 = Option<i64>;
+    type Output2 = Option<i64>;
+    const PARAMS: &'static [Param] = &[Param {
+        name: "top",
+        default: 3,
+        max: stats::MAX_TOP as u64,
+        help: "how many of the best-stocked elves part 2 adds up",
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input, _: &Params) -> Self::Output1 {
+        solution_1(input)
+    }
+
+    fn part_2(input: &Self::Input, params: &Params) -> Self::Output2 {
+        solution_2(input, params.value::<usize>("top"))
+    }
+}
+
+/// Groups the calorie counts by elf, with elves separated by empty lines.
+pub fn parse_input(input: &str) -> Result<Vec<Elf>, ParseError> {
+    parse_input_with(input, Whitespace::Strict)
+}
+
+pub fn parse_input_with(input: &str, whitespace: Whitespace) -> Result<Vec<Elf>, ParseError> {
+    let mut elves = Vec::new();
+    let mut items = Vec::new();
+    for line in lines(input) {
+        match parse_line(&line, whitespace)? {
+            Some(calories) => items.push(calories),
+            None if items.is_empty() => {}
+            None => elves.push(Elf {
+                index: elves.len(),
+                items: std::mem::take(&mut items),
+            }),
+        }
+    }
+    if !items.is_empty() {
+        elves.push(Elf {
+            index: elves.len(),
+            items,
+        });
+    }
+    Ok(elves)
+}
+
+/// The calories on a line, or `None` if the line separates elves.
+fn parse_line<T: FromStr>(line: &Line, whitespace: Whitespace) -> Result<Option<T>, ParseError> {
+    let text = match whitespace {
+        Whitespace::Strict => line.text,
+        Whitespace::Trim => line.text.trim(),
+    };
+    if text.is_empty() {
+        return Ok(None);
+    }
+    let trimmed = text.trim();
+    if trimmed.is_empty() {
+        return Err(line.error(1, "expected an empty line between elves, not whitespace"));
+    }
+    if trimmed.len() != text.len() && trimmed.parse::<T>().is_ok() {
+        return Err(line.error_at(
+            trimmed,
+            format!("unexpected whitespace around `{}`", trimmed),
+        ));
+    }
+    line.parse_at(text).map(Some)
+}
+
+/// What each elf carries in total, summed in `i64` so that no count can overflow it.
+fn totals(elves: &[Elf]) -> impl Iterator<Item = i64> + '_ {
+    elves
+        .iter()
+        .map(|elf| elf.items.iter().map(|&calories| i64::from(calories)).sum())
+}
+
+/// The most calories carried by one elf, or `None` if there are no elves.
+pub fn solution_1(elves: &[Elf]) -> Option<i64> {
+    totals(elves).max()
+}
+
+/// The calories carried by the `top` best-stocked elves together, or `None` if there are
+/// fewer elves than that or their calories overflow an `i64`.
+pub fn solution_2(elves: &[Elf], top: usize) -> Option<i64> {
+    if elves.len() < top {
+        return None;
+    }
+    let mut best = TopK::new(top);
+    for (elf, total) in elves.iter().zip(totals(elves)) {
+        best.push(elf.index, total);
+    }
+    best.total()
+}
+
+/// Statistics over every elf's total, keeping the best `top` elves.
+pub fn stats(elves: &[Elf], top: usize) -> Stats {
+    let mut stats = Stats::new(top);
+    for (elf, total) in elves.iter().zip(totals(elves)) {
+        stats.push(elf.index, total);
+    }
+    stats
+}
+
+#[test]
+fn test_totals_do_not_overflow() {
+    let input = parse_input("2147483647\n2147483647\n\n-2147483648\n-2147483648\n").unwrap();
+    assert_eq!(solution_1(&input), Some(4294967294));
+    assert_eq!(solution_2(&input, 2), Some(-2));
+}
+
+#[test]
+fn test_parse_input() {
+    let elves = parse_input("1000\n2000\n\n\n4000\r\n\r\n5000\n").unwrap();
+    assert_eq!(
+        elves,
+        [
+            Elf {
+                index: 0,
+                items: vec![1000, 2000]
+            },
+            Elf {
+                index: 1,
+                items: vec![4000]
+            },
+            Elf {
+                index: 2,
+                items: vec![5000]
+            },
+        ]
+    );
+    assert_eq!(parse_input("").unwrap(), []);
+}
+
+#[test]
+fn test_parse_errors() {
+    let error = |input: &str, whitespace| parse_input_with(input, whitespace).unwrap_err();
+    assert_eq!(
+        error("1000\n12a4\n\n3000\n", Whitespace::Strict),
+        ParseError::new(2, 1, "invalid number `12a4`")
+    );
+    assert_eq!(
+        error("1000\n12a4\n", Whitespace::Trim),
+        ParseError::new(2, 1, "invalid number `12a4`")
+    );
+    assert_eq!(
+        error("1000\n  2000 \n", Whitespace::Strict),
+        ParseError::new(2, 3, "unexpected whitespace around `2000`")
+    );
+    assert_eq!(
+        error("1000\n \n2000\n", Whitespace::Strict),
+        ParseError::new(2, 1, "expected an empty line between elves, not whitespace")
+    );
+    assert_eq!(
+        error("1000\n20 00\n", Whitespace::Trim),
+        ParseError::new(2, 1, "invalid number `20 00`")
+    );
+}
+
+#[test]
+fn test_parse_trimmed() {
+    let elves = parse_input_with("1000 \r\n\t2000\r\n \r\n3000\r", Whitespace::Trim).unwrap();
+    assert_eq!(solution_1(&elves), Some(3000));
+    assert_eq!(solution_2(&elves, 2), Some(6000));
+}
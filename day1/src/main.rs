@@ -1,39 +1,71 @@
-use std::io::stdin;
+use std::{env::args, process::exit};
 
-use itertools::Itertools;
-use std::collections::BinaryHeap;
+use aoc_common::{
+    solution::{parse_args, print_solution, read_and_parse, run},
+    InputSource, Solution,
+};
+use day1::{
+    stats::{Stats, StatsArgs},
+    stream::stream_stats,
+    Day1, Whitespace,
+};
 
 fn main() {
-    let input = parse_input();
-    println!("solution 1: {:?}", solution_1(&input));
-    println!("solution 2: {:?}", solution_2(&input));
-}
-
-fn parse_input() -> Vec<Vec<i32>> {
-    let lines_parsed = stdin().lines().map(|line| line.unwrap().parse::<i32>());
-
-    let mut groups = Vec::new();
-    for (key, group) in &lines_parsed.group_by(|x| x.is_ok()) {
-        if key {
-            groups.push(group.map(|x| x.unwrap()).collect_vec());
+    let mut args: Vec<String> = args().skip(1).collect();
+    let stream = match args.iter().position(|arg| arg == "--stream") {
+        Some(i) => {
+            args.remove(i);
+            true
+        }
+        None => false,
+    };
+    let parsed = StatsArgs::take(&mut args)
+        .and_then(|stats_args| Ok((stats_args, parse_args::<Day1>(args)?)));
+    let (stats_args, (source, params)) = match parsed {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            eprintln!(
+                "usage: day{} [--input <path> | --example | -] [--param <name=value>]... \
+                 [--stream] {}",
+                Day1::DAY,
+                StatsArgs::USAGE
+            );
+            exit(2);
+        }
+    };
+    if !stream && stats_args.is_none() {
+        return run::<Day1>();
+    }
+    let top = stats_args
+        .as_ref()
+        .and_then(|stats_args| stats_args.top)
+        .unwrap_or_else(|| params.value::<usize>("top"));
+    let stats = if stream {
+        streamed(&source, top)
+    } else {
+        day1::stats(&read_and_parse::<Day1>(&source), top)
+    };
+    match stats_args {
+        Some(stats_args) => println!(
+            "{}",
+            stats.summary(&stats_args.percentiles, stats_args.bars)
+        ),
+        None => {
+            print_solution(1, &stats.max());
+            print_solution(2, &stats.top_total());
         }
     }
-    groups
-}
-
-fn solution_1(input: &Vec<Vec<i32>>) -> Option<i32> {
-    input.iter().map(|group| group.iter().sum()).max()
 }
 
-fn solution_2(input: &Vec<Vec<i32>>) -> Option<i32> {
-    let group_totals = input.iter().map(|group| group.iter().sum::<i32>());
-    let mut heap = BinaryHeap::from_iter(group_totals);
-    let mut sum = 0;
-    for _ in 0..3 {
-        match heap.pop() {
-            Some(val) => sum += val,
-            None => return None,
-        };
-    }
-    Some(sum)
+/// Totals the input a line at a time, without reading it all in.
+fn streamed(source: &InputSource, top: usize) -> Stats {
+    let reader = source.open(Day1::DAY).unwrap_or_else(|e| {
+        eprintln!("error: {}", e);
+        exit(1);
+    });
+    stream_stats(reader, Whitespace::Strict, top).unwrap_or_else(|e| {
+        eprintln!("error: {}: {}", source.name(Day1::DAY), e);
+        exit(1);
+    })
 }
@@ -0,0 +1,456 @@
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, BinaryHeap},
+    fmt::Write,
+};
+
+/// How many histogram buckets `Stats` keeps before merging them in pairs.
+const MAX_BUCKETS: usize = 1024;
+
+/// The most elves `--top` keeps. The puzzle has a few hundred elves, and every kept elf is
+/// held in memory and printed on its own line, so more than this is never useful.
+pub const MAX_TOP: usize = 100_000;
+
+/// The most bars `--histogram` draws, each on its own line.
+const MAX_BARS: usize = 1000;
+
+/// The `k` elves carrying the most, seen one at a time. Ties go to the elf that came first.
+#[derive(Debug, Clone)]
+pub struct TopK {
+    k: usize,
+    /// The best elves so far as `(total, index)`, with the worst of them on top.
+    heap: BinaryHeap<Reverse<(i64, Reverse<usize>)>>,
+}
+
+impl TopK {
+    pub fn new(k: usize) -> Self {
+        Self {
+            k,
+            heap: BinaryHeap::new(),
+        }
+    }
+
+    pub fn push(&mut self, index: usize, total: i64) {
+        self.heap.push(Reverse((total, Reverse(index))));
+        if self.heap.len() > self.k {
+            self.heap.pop();
+        }
+    }
+
+    /// The elves kept as `(index, total)`, best first.
+    pub fn elves(&self) -> Vec<(usize, i64)> {
+        let mut elves: Vec<_> = self
+            .heap
+            .iter()
+            .map(|&Reverse((total, Reverse(index)))| (index, total))
+            .collect();
+        elves.sort_by_key(|&(index, total)| (Reverse(total), index));
+        elves
+    }
+
+    /// The kept elves' totals added up, or `None` if that overflows.
+    pub fn total(&self) -> Option<i64> {
+        self.heap
+            .iter()
+            .try_fold(0i64, |sum, &Reverse((total, _))| sum.checked_add(total))
+    }
+}
+
+/// One bar of a histogram, counting the totals from `start` up to but not including `end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bar {
+    pub start: i64,
+    pub end: i64,
+    pub count: u64,
+}
+
+/// Statistics over elf totals, gathered one elf at a time in memory bounded by `k` and
+/// `MAX_BUCKETS`. Percentiles are exact until more than `MAX_BUCKETS` different totals have
+/// been seen, and are estimated within a bucket after that.
+#[derive(Debug, Clone)]
+pub struct Stats {
+    top: TopK,
+    count: u64,
+    sum: i128,
+    min: i64,
+    max: i64,
+    /// How many totals fall in each `width` wide bucket, keyed by `total.div_euclid(width)`.
+    buckets: BTreeMap<i64, u64>,
+    width: i64,
+}
+
+impl Stats {
+    /// Keeps the best `k` elves.
+    pub fn new(k: usize) -> Self {
+        Self {
+            top: TopK::new(k),
+            count: 0,
+            sum: 0,
+            min: i64::MAX,
+            max: i64::MIN,
+            buckets: BTreeMap::new(),
+            width: 1,
+        }
+    }
+
+    pub fn push(&mut self, index: usize, total: i64) {
+        self.top.push(index, total);
+        self.count += 1;
+        self.sum += i128::from(total);
+        self.min = self.min.min(total);
+        self.max = self.max.max(total);
+        *self
+            .buckets
+            .entry(total.div_euclid(self.width))
+            .or_default() += 1;
+        while self.buckets.len() > MAX_BUCKETS {
+            self.width *= 2;
+            let mut merged = BTreeMap::new();
+            for (key, count) in std::mem::take(&mut self.buckets) {
+                *merged.entry(key.div_euclid(2)).or_default() += count;
+            }
+            self.buckets = merged;
+        }
+    }
+
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// The best elves as `(index, total)`, best first.
+    pub fn top(&self) -> Vec<(usize, i64)> {
+        self.top.elves()
+    }
+
+    /// What the best `k` elves carry together, as part 2 has it: `None` if there are fewer
+    /// than `k` elves or their total overflows.
+    pub fn top_total(&self) -> Option<i64> {
+        if self.count < self.top.k as u64 {
+            return None;
+        }
+        self.top.total()
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        (self.count > 0).then_some(self.min)
+    }
+
+    pub fn max(&self) -> Option<i64> {
+        (self.count > 0).then_some(self.max)
+    }
+
+    pub fn mean(&self) -> Option<f64> {
+        (self.count > 0).then(|| self.sum as f64 / self.count as f64)
+    }
+
+    pub fn median(&self) -> Option<f64> {
+        self.percentile(50.0)
+    }
+
+    /// The total that `p` percent of elves carry no more than, interpolating between elves.
+    pub fn percentile(&self, p: f64) -> Option<f64> {
+        assert!((0.0..=100.0).contains(&p), "percentiles go from 0 to 100");
+        if self.count == 0 {
+            return None;
+        }
+        let rank = p / 100.0 * (self.count - 1) as f64;
+        let (below, above) = (rank.floor(), rank.ceil());
+        let (low, high) = (self.nth(below as u64), self.nth(above as u64));
+        Some(low + (high - low) * (rank - below))
+    }
+
+    /// The `n`th smallest total counting from zero, or an estimate of it once buckets are
+    /// wider than one.
+    fn nth(&self, n: u64) -> f64 {
+        let mut seen = 0;
+        for (&key, &count) in &self.buckets {
+            if n < seen + count {
+                if self.width == 1 {
+                    return key as f64;
+                }
+                // Assume the bucket's totals are spread evenly across it.
+                let width = self.width as f64;
+                let estimate =
+                    key as f64 * width + width * ((n - seen) as f64 + 0.5) / count as f64;
+                return estimate.clamp(self.min as f64, self.max as f64);
+            }
+            seen += count;
+        }
+        unreachable!("n is less than the count")
+    }
+
+    /// Splits the totals from the smallest to the largest into `bars` bars of equal width.
+    pub fn histogram(&self, bars: usize) -> Vec<Bar> {
+        if self.count == 0 || bars == 0 {
+            return Vec::new();
+        }
+        let span = i128::from(self.max) - i128::from(self.min) + 1;
+        let bar_width = ((span + bars as i128 - 1) / bars as i128).max(1);
+        let bars = ((span + bar_width - 1) / bar_width) as usize;
+        let mut histogram: Vec<Bar> = (0..bars)
+            .map(|i| {
+                let start = i128::from(self.min) + i as i128 * bar_width;
+                Bar {
+                    start: start as i64,
+                    end: (start + bar_width).min(i128::from(i64::MAX)) as i64,
+                    count: 0,
+                }
+            })
+            .collect();
+        for (&key, &count) in &self.buckets {
+            // Wide buckets are counted in the bar holding their middle.
+            let middle = i128::from(key) * i128::from(self.width) + i128::from(self.width) / 2;
+            let middle = middle.clamp(i128::from(self.min), i128::from(self.max));
+            let bar = ((middle - i128::from(self.min)) / bar_width) as usize;
+            histogram[bar].count += count;
+        }
+        histogram
+    }
+
+    /// A summary for people to read, with the given percentiles and a histogram of `bars`
+    /// bars if there are any.
+    pub fn summary(&self, percentiles: &[f64], bars: usize) -> String {
+        let (Some(mean), Some(median), Some(min), Some(max)) =
+            (self.mean(), self.median(), self.min(), self.max())
+        else {
+            return "no elves".to_owned();
+        };
+        let mut out = String::new();
+        let _ = writeln!(out, "elves: {}", self.count);
+        let _ = writeln!(out, "min: {}  max: {}", min, max);
+        let _ = writeln!(out, "mean: {:.1}  median: {:.1}", mean, median);
+        for &p in percentiles {
+            let _ = writeln!(out, "p{}: {:.1}", p, self.percentile(p).unwrap_or_default());
+        }
+        let top = self.top();
+        if !top.is_empty() {
+            let _ = writeln!(out, "top {}:", top.len());
+            for (index, total) in top {
+                let _ = writeln!(out, "  elf {:>6}  {}", index, total);
+            }
+        }
+        let histogram = self.histogram(bars);
+        let tallest = histogram.iter().map(|bar| bar.count).max().unwrap_or(0);
+        if !histogram.is_empty() {
+            let _ = writeln!(out, "histogram:");
+        }
+        for bar in histogram {
+            let length = (bar.count * 40).div_ceil(tallest.max(1)) as usize;
+            let _ = writeln!(
+                out,
+                "  {:>12}..{:<12} {:>6} {}",
+                bar.start,
+                bar.end,
+                bar.count,
+                "#".repeat(length)
+            );
+        }
+        out.trim_end().to_owned()
+    }
+}
+
+/// The flags that make `day1` print statistics instead of its answers.
+#[derive(Debug, Default, PartialEq)]
+pub struct StatsArgs {
+    /// How many of the best elves to list, if not the `top` parameter.
+    pub top: Option<usize>,
+    pub percentiles: Vec<f64>,
+    /// How many bars the histogram has, with none meaning no histogram.
+    pub bars: usize,
+}
+
+impl StatsArgs {
+    pub const USAGE: &'static str =
+        "[--stats] [--top <k>] [--percentile <p>]... [--histogram <bars>]";
+
+    /// Takes `--stats`, `--top`, `--percentile` and `--histogram` out of `args`, returning
+    /// `None` if none of them were there.
+    pub fn take(args: &mut Vec<String>) -> Result<Option<Self>, String> {
+        let mut stats = StatsArgs::default();
+        let mut found = false;
+        let mut rest = Vec::new();
+        let mut taken = std::mem::take(args).into_iter();
+        while let Some(arg) = taken.next() {
+            let (flag, inline) = match arg.split_once('=') {
+                Some((flag, value)) => (flag.to_owned(), Some(value.to_owned())),
+                None => (arg.clone(), None),
+            };
+            if !["--stats", "--top", "--percentile", "--histogram"].contains(&flag.as_str()) {
+                rest.push(arg);
+                continue;
+            }
+            found = true;
+            if flag == "--stats" {
+                continue;
+            }
+            let value = inline
+                .or_else(|| taken.next())
+                .ok_or_else(|| format!("{} needs a value", flag))?;
+            let invalid = || format!("invalid {} `{}`", flag, value);
+            let count = |max: usize| match value.parse() {
+                Ok(count) if (1..=max).contains(&count) => Ok(count),
+                _ => Err(format!("{}, expected 1 to {}", invalid(), max)),
+            };
+            match flag.as_str() {
+                "--top" => stats.top = Some(count(MAX_TOP)?),
+                "--histogram" => stats.bars = count(MAX_BARS)?,
+                _ => match value.parse::<f64>() {
+                    Ok(p) if (0.0..=100.0).contains(&p) => stats.percentiles.push(p),
+                    _ => return Err(format!("{}, expected 0 to 100", invalid())),
+                },
+            }
+        }
+        *args = rest;
+        Ok(found.then_some(stats))
+    }
+}
+
+#[test]
+fn test_top_k() {
+    let mut top = TopK::new(3);
+    for (index, total) in [(0, 6000), (1, 4000), (2, 11000), (3, 24000), (4, 11000)] {
+        top.push(index, total);
+    }
+    assert_eq!(top.elves(), [(3, 24000), (2, 11000), (4, 11000)]);
+    assert_eq!(top.total(), Some(46000));
+    let mut none = TopK::new(0);
+    none.push(0, 1);
+    assert_eq!(none.elves(), []);
+}
+
+#[test]
+fn test_stats() {
+    let mut stats = Stats::new(2);
+    assert_eq!(stats.summary(&[90.0], 4), "no elves");
+    for (index, total) in [6000, 4000, 11000, 24000, 10000].into_iter().enumerate() {
+        stats.push(index, total);
+    }
+    assert_eq!(stats.count(), 5);
+    assert_eq!(stats.mean(), Some(11000.0));
+    assert_eq!(stats.median(), Some(10000.0));
+    assert_eq!(stats.percentile(0.0), Some(4000.0));
+    assert_eq!(stats.percentile(100.0), Some(24000.0));
+    assert_eq!(stats.percentile(12.5), Some(5000.0));
+    assert_eq!(stats.top(), [(3, 24000), (2, 11000)]);
+    assert_eq!(stats.top_total(), Some(35000));
+    assert_eq!(Stats::new(6).top_total(), None);
+    assert_eq!(
+        stats.histogram(2),
+        [
+            Bar {
+                start: 4000,
+                end: 14001,
+                count: 4
+            },
+            Bar {
+                start: 14001,
+                end: 24002,
+                count: 1
+            },
+        ]
+    );
+    assert_eq!(
+        stats.summary(&[90.0], 2),
+        "elves: 5\nmin: 4000  max: 24000\nmean: 11000.0  median: 10000.0\np90: 18800.0\n\
+         top 2:\n  elf      3  24000\n  elf      2  11000\nhistogram:\n\
+         \x20         4000..14001             4 ########################################\n\
+         \x20        14001..24002             1 ##########"
+    );
+}
+
+#[test]
+fn test_stats_stay_bounded() {
+    let mut stats = Stats::new(3);
+    for index in 0..100_000 {
+        stats.push(index, index as i64 * 7 - 300_000);
+    }
+    assert!(stats.buckets.len() <= MAX_BUCKETS);
+    assert_eq!(stats.min(), Some(-300_000));
+    assert_eq!(stats.max(), Some(399_993));
+    assert_eq!(stats.mean(), Some(49_996.5));
+    let median = stats.median().unwrap();
+    assert!(
+        (median - 49_996.5).abs() <= stats.width as f64,
+        "{}",
+        median
+    );
+    assert_eq!(stats.top()[0], (99_999, 399_993));
+    let histogram = stats.histogram(10);
+    assert_eq!(histogram.iter().map(|bar| bar.count).sum::<u64>(), 100_000);
+    assert!(histogram
+        .iter()
+        .all(|bar| bar.count.abs_diff(10_000) <= 100));
+}
+
+#[test]
+fn test_extreme_totals() {
+    let mut stats = Stats::new(1);
+    stats.push(0, i64::MIN);
+    stats.push(1, i64::MAX);
+    assert_eq!(stats.mean(), Some(-0.5));
+    assert_eq!(
+        stats.histogram(3).iter().map(|bar| bar.count).sum::<u64>(),
+        2
+    );
+    assert_eq!(stats.top_total(), Some(i64::MAX));
+    let mut stats = Stats::new(2);
+    stats.push(0, i64::MAX);
+    stats.push(1, 1);
+    assert_eq!(stats.top_total(), None);
+}
+
+#[test]
+fn test_take_stats_args() {
+    let take = |args: &[&str]| {
+        let mut args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+        StatsArgs::take(&mut args).map(|stats| (stats, args))
+    };
+    assert_eq!(
+        take(&["--example", "--param", "top=2"]),
+        Ok((
+            None,
+            vec![
+                "--example".to_owned(),
+                "--param".to_owned(),
+                "top=2".to_owned()
+            ]
+        ))
+    );
+    assert_eq!(
+        take(&[
+            "--top",
+            "5",
+            "--percentile=90",
+            "-",
+            "--percentile",
+            "99.5",
+            "--histogram=8"
+        ]),
+        Ok((
+            Some(StatsArgs {
+                top: Some(5),
+                percentiles: vec![90.0, 99.5],
+                bars: 8
+            }),
+            vec!["-".to_owned()]
+        ))
+    );
+    assert_eq!(take(&["--stats"]), Ok((Some(StatsArgs::default()), vec![])));
+    assert_eq!(take(&["--top"]), Err("--top needs a value".to_owned()));
+    assert_eq!(
+        take(&["--top", "0"]),
+        Err("invalid --top `0`, expected 1 to 100000".to_owned())
+    );
+    assert_eq!(
+        take(&["--top=100000000000"]),
+        Err("invalid --top `100000000000`, expected 1 to 100000".to_owned())
+    );
+    assert_eq!(
+        take(&["--histogram", "18446744073709551615"]),
+        Err("invalid --histogram `18446744073709551615`, expected 1 to 1000".to_owned())
+    );
+    assert_eq!(
+        take(&["--percentile", "101"]),
+        Err("invalid --percentile `101`, expected 0 to 100".to_owned())
+    );
+}
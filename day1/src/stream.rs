@@ -0,0 +1,199 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    io::{self, BufRead, Read},
+    str,
+};
+
+use aoc_common::{parse::Line, ParseError};
+
+use crate::{parse_line, stats::Stats, Whitespace};
+
+/// The longest line read, so that input without newlines cannot fill memory.
+const MAX_LINE_LEN: usize = 1024;
+
+/// Why streamed calories could not be totalled.
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+    /// An elf's calories add up to more than an `i64` can hold, which takes billions of lines.
+    Overflow {
+        elf: usize,
+        line: usize,
+    },
+}
+
+impl Display for StreamError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StreamError::Io(e) => write!(f, "{}", e),
+            StreamError::Parse(e) => write!(f, "{}", e),
+            StreamError::Overflow { elf, line } => write!(
+                f,
+                "line {}: the calories of elf {} overflow a 64-bit total",
+                line, elf
+            ),
+        }
+    }
+}
+
+impl Error for StreamError {}
+
+impl From<io::Error> for StreamError {
+    fn from(e: io::Error) -> Self {
+        StreamError::Io(e)
+    }
+}
+
+impl From<ParseError> for StreamError {
+    fn from(e: ParseError) -> Self {
+        StreamError::Parse(e)
+    }
+}
+
+/// Reads elves from `reader` a line at a time, handing each one's index and total to `on_elf`,
+/// and returns how many there were. Only the current line and total are held in memory, and
+/// each line holds an `i32`, as it does for [`parse_input`](crate::parse_input).
+pub fn stream_totals(
+    mut reader: impl BufRead,
+    whitespace: Whitespace,
+    mut on_elf: impl FnMut(usize, i64),
+) -> Result<usize, StreamError> {
+    let mut buffer = Vec::new();
+    let mut number = 0;
+    let mut elves = 0;
+    let mut total: Option<i64> = None;
+    loop {
+        buffer.clear();
+        // Enough for the longest line and a `\r\n` after it.
+        let limit = MAX_LINE_LEN as u64 + 2;
+        if (&mut reader).take(limit).read_until(b'\n', &mut buffer)? == 0 {
+            break;
+        }
+        number += 1;
+        // Lines end in `\n` or `\r\n`, as `str::lines` has them.
+        let bytes = match buffer.strip_suffix(b"\n") {
+            Some(line) => line.strip_suffix(b"\r").unwrap_or(line),
+            None => &buffer,
+        };
+        if bytes.len() > MAX_LINE_LEN {
+            let message = format!("line is longer than {} bytes", MAX_LINE_LEN);
+            return Err(ParseError::new(number, 1, message).into());
+        }
+        let text = str::from_utf8(bytes).map_err(|e| {
+            let valid = str::from_utf8(&bytes[..e.valid_up_to()]).unwrap_or_default();
+            ParseError::new(number, valid.chars().count() + 1, "invalid UTF-8")
+        })?;
+        match parse_line::<i32>(&Line { number, text }, whitespace)? {
+            Some(calories) => {
+                let overflow = StreamError::Overflow {
+                    elf: elves,
+                    line: number,
+                };
+                total = Some(add(total, calories).ok_or(overflow)?);
+            }
+            None => {
+                if let Some(total) = total.take() {
+                    on_elf(elves, total);
+                    elves += 1;
+                }
+            }
+        }
+    }
+    if let Some(total) = total {
+        on_elf(elves, total);
+        elves += 1;
+    }
+    Ok(elves)
+}
+
+/// Adds a snack to an elf's total so far, if that fits.
+fn add(total: Option<i64>, calories: i32) -> Option<i64> {
+    total.unwrap_or(0).checked_add(i64::from(calories))
+}
+
+/// Streams `reader` into statistics that keep the best `top` elves.
+pub fn stream_stats(
+    reader: impl BufRead,
+    whitespace: Whitespace,
+    top: usize,
+) -> Result<Stats, StreamError> {
+    let mut stats = Stats::new(top);
+    stream_totals(reader, whitespace, |index, total| stats.push(index, total))?;
+    Ok(stats)
+}
+
+#[cfg(test)]
+fn totals(input: &[u8]) -> Result<Vec<(usize, i64)>, String> {
+    let mut totals = Vec::new();
+    stream_totals(input, Whitespace::Strict, |index, total| {
+        totals.push((index, total))
+    })
+    .map_err(|e| e.to_string())?;
+    Ok(totals)
+}
+
+#[test]
+fn test_stream_totals() {
+    assert_eq!(
+        totals(b"1000\n2000\n\n\n4000\r\n\r\n5000"),
+        Ok(vec![(0, 3000), (1, 4000), (2, 5000)])
+    );
+    assert_eq!(totals(b""), Ok(vec![]));
+    assert_eq!(
+        totals(b"1000\n12a4\n"),
+        Err("line 2, column 1: invalid number `12a4`".to_owned())
+    );
+    assert_eq!(
+        totals(b"1\n\n2\xff\n"),
+        Err("line 3, column 2: invalid UTF-8".to_owned())
+    );
+    assert_eq!(
+        totals(&[b'1'; MAX_LINE_LEN + 1]),
+        Err("line 1, column 1: line is longer than 1024 bytes".to_owned())
+    );
+    let mut long = vec![b'0'; MAX_LINE_LEN - 1];
+    long.extend(b"7\n");
+    assert_eq!(totals(&long), Ok(vec![(0, 7)]));
+}
+
+#[test]
+fn test_stream_long_lines() {
+    let mut long = vec![b'0'; MAX_LINE_LEN - 1];
+    long.extend(b"7\r\n\r\n3\r\n");
+    assert_eq!(totals(&long), Ok(vec![(0, 7), (1, 3)]));
+    let mut too_long = vec![b'0'; MAX_LINE_LEN];
+    too_long.extend(b"7\r\n");
+    assert_eq!(
+        totals(&too_long),
+        Err("line 1, column 1: line is longer than 1024 bytes".to_owned())
+    );
+    let mut too_long = vec![b'0'; MAX_LINE_LEN];
+    too_long.extend(b"7\n");
+    assert_eq!(
+        totals(&too_long),
+        Err("line 1, column 1: line is longer than 1024 bytes".to_owned())
+    );
+}
+
+#[test]
+fn test_stream_overflow() {
+    // Each line holds an `i32`, as when parsing the whole input.
+    assert_eq!(
+        totals(b"2147483647\n2147483647\n\n-2147483648\n"),
+        Ok(vec![(0, 4294967294), (1, -2147483648)])
+    );
+    assert_eq!(
+        totals(b"1\n\n2147483648\n"),
+        Err("line 3, column 1: invalid number `2147483648`".to_owned())
+    );
+    assert_eq!(add(None, -5), Some(-5));
+    assert_eq!(add(Some(i64::MAX - 1), 1), Some(i64::MAX));
+    assert_eq!(add(Some(i64::MAX), 1), None);
+    assert_eq!(add(Some(i64::MIN), -1), None);
+    assert_eq!(
+        StreamError::Overflow { elf: 1, line: 5 }.to_string(),
+        "line 5: the calories of elf 1 overflow a 64-bit total"
+    );
+}
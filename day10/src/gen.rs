@@ -0,0 +1,32 @@
+use aoc_common::{Generate, Rng};
+
+use crate::Day10;
+
+/// `size` is the number of instructions. Programs always run for at least the 240 cycles the
+/// CRT needs, and keep the sprite roughly on screen.
+impl Generate for Day10 {
+    const DEFAULT_SIZE: usize = 140;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut out = String::new();
+        let mut x = 1;
+        let mut cycles = 0;
+        let mut instructions = 0;
+        while instructions < size || cycles < 240 {
+            if rng.chance(1, 3) {
+                out.push_str("noop\n");
+                cycles += 1;
+            } else {
+                let mut v = rng.range(-15..=15);
+                if !(-5..=45).contains(&(x + v)) {
+                    v = -v;
+                }
+                x += v;
+                out.push_str(&format!("addx {}\n", v));
+                cycles += 2;
+            }
+            instructions += 1;
+        }
+        out
+    }
+}
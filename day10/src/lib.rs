@@ -0,0 +1,237 @@
+//! Day 10: Cathode-Ray Tube, running a tiny CPU that drives a 40x6 screen.
+
+pub mod gen;
+
+use std::fmt::Display;
+
+use aoc_common::{lines, Answer, Param, Params, ParseError, Solution};
+
+/// An instruction: `addx` takes two cycles and `noop` one.
+#[derive(Debug, Clone)]
+pub enum Command {
+    AddX(i32),
+    NoOp,
+}
+
+/// The pixels drawn on the CRT, one row per line.
+pub struct Screen(pub Vec<Vec<char>>);
+
+impl Display for Screen {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rows: Vec<String> = self.0.iter().map(|row| row.iter().collect()).collect();
+        write!(f, "{}", rows.join("\n"))
+    }
+}
+
+/// The capital letters the CRT can draw, each 4 pixels wide and 6 tall, top row first.
+const FONT: [(char, [&str; 6]); 18] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...", "#...", ".#.#", "..#.", "..#.", "..#."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+impl Screen {
+    /// Reads the letters drawn on the screen, five columns apart, or `None` if any of them is
+    /// not a letter of the font.
+    pub fn read_letters(&self) -> Option<String> {
+        let rows: Vec<String> = self.0.iter().map(|row| row.iter().collect()).collect();
+        if rows.len() != 6 {
+            return None;
+        }
+        let width = rows[0].len();
+        (0..width)
+            .step_by(5)
+            .map(|start| {
+                let glyph: Vec<&str> = rows
+                    .iter()
+                    .map(|row| row.get(start..start + 4))
+                    .collect::<Option<_>>()?;
+                FONT.iter()
+                    .find(|(_, pixels)| pixels[..] == glyph[..])
+                    .map(|(letter, _)| *letter)
+            })
+            .collect()
+    }
+}
+
+impl Answer for Screen {
+    fn answer(&self) -> Option<String> {
+        Some(self.read_letters().unwrap_or_else(|| self.to_string()))
+    }
+
+    fn diagnostics(&self) -> Option<String> {
+        self.read_letters().map(|_| self.to_string())
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input = Vec<Command>;
+    type Output1 = Option<i64>;
+    type Output2 = Screen;
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "width",
+            default: 40,
+            max: usize::MAX as u64,
+            help: "how many pixels the CRT draws per row",
+        },
+        Param {
+            name: "height",
+            default: 6,
+            max: usize::MAX as u64,
+            help: "how many rows the CRT draws",
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input, _: &Params) -> Self::Output1 {
+        solution_1(input)
+    }
+
+    fn part_2(input: &Self::Input, params: &Params) -> Self::Output2 {
+        let (width, height) = (params.value("width"), params.value("height"));
+        Screen(solution_2(input, width, height))
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Command>, ParseError> {
+    use Command::*;
+    lines(input)
+        .map(
+            |line| match line.text.split(' ').collect::<Vec<&str>>().as_slice() {
+                ["noop"] => Ok(NoOp),
+                ["addx", x] => Ok(AddX(line.parse_at(x)?)),
+                _ => Err(line.error(1, format!("unknown instruction: {}", line.text))),
+            },
+        )
+        .collect()
+}
+
+/// Sums the signal strengths during cycles 20, 60, ..., 220, or `None` if the program
+/// ends too early.
+pub fn solution_1(commands: &[Command]) -> Option<i64> {
+    let desired_cycles = [20, 60, 100, 140, 180, 220];
+    let states = normalize_steps(&steps(commands));
+    let mut states = states.iter();
+    let signal_strengths: Option<Vec<i64>> = desired_cycles
+        .iter()
+        .map(|&desired_cycle| {
+            states
+                .find(|state| state.clock_cycle == desired_cycle)
+                .map(|state| state.x_value * desired_cycle as i64)
+        })
+        .collect();
+    signal_strengths.map(|vals| vals.iter().sum())
+}
+
+/// The pixels the program draws on a `width` by `height` screen, `#` lit and `.` dark. Rows
+/// stop early if the program does.
+pub fn solution_2(commands: &[Command], width: usize, height: usize) -> Vec<Vec<char>> {
+    let states = normalize_steps(&steps(commands));
+    states
+        .chunks(width.max(1))
+        .take(height)
+        .map(|row| {
+            row.iter()
+                .enumerate()
+                .map(|(col, state)| {
+                    if (state.x_value - col as i64).abs() <= 1 {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn normalize_steps(states: &[State]) -> Vec<State> {
+    states
+        .windows(2)
+        .flat_map(|window| {
+            (window[0].clock_cycle..window[1].clock_cycle).map(|clock_cycle| State {
+                x_value: window[0].x_value,
+                clock_cycle,
+            })
+        })
+        .chain([states.last().unwrap().clone()])
+        .collect()
+}
+
+fn steps(commands: &[Command]) -> Vec<State> {
+    let mut states = Vec::new();
+    states.push(State {
+        x_value: 1,
+        clock_cycle: 1,
+    });
+    let scan = commands.iter().scan(states[0].clone(), step);
+    for state in scan {
+        states.push(state);
+    }
+    states
+}
+
+#[derive(Debug, Clone)]
+struct State {
+    /// Wide enough that adding every `addx` in a long program cannot overflow it.
+    x_value: i64,
+    clock_cycle: usize,
+}
+
+fn step(state: &mut State, command: &Command) -> Option<State> {
+    use Command::*;
+    match command {
+        NoOp => {
+            state.clock_cycle += 1;
+        }
+        AddX(x) => {
+            state.x_value += i64::from(*x);
+            state.clock_cycle += 2;
+        }
+    };
+    Some(state.clone())
+}
+
+#[test]
+fn test_read_letters() {
+    let glyph = |letter: char| FONT.iter().find(|(c, _)| *c == letter).unwrap().1;
+    let (h, i) = (glyph('H'), glyph('I'));
+    let rows = (0..6)
+        .map(|row| format!("{}.{}.", h[row], i[row]).chars().collect())
+        .collect();
+    assert_eq!(Screen(rows).read_letters(), Some("HI".to_owned()));
+
+    let input = include_str!("../example.txt");
+    let screen = Screen(solution_2(&parse_input(input).unwrap(), 40, 6));
+    assert_eq!(screen.read_letters(), None);
+    assert_eq!(screen.answer(), Some(screen.to_string()));
+}
+
+#[test]
+fn test_signal_strength_does_not_overflow() {
+    let input = "addx 2147483647\n".repeat(120);
+    let commands = parse_input(&input).unwrap();
+    assert_eq!(solution_1(&commands), Some(56480 * 2147483647 + 720));
+}
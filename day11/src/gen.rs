@@ -0,0 +1,75 @@
+use aoc_common::{Generate, Rng};
+
+use crate::{first_overflow_1, Day11, Monkey, Operation};
+
+/// Divisors are small primes so part 2's worry levels, kept below their product, can still be
+/// squared without overflowing.
+const DIVISORS: [u64; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+/// `size` is the number of monkeys (at least two).
+///
+/// Part 1 never reduces worry levels modulo anything, so each monkey that overflows a `u64` in
+/// its 20 rounds is given an addition instead. Additions cannot overflow, so this ends after at
+/// most one change per monkey.
+impl Generate for Day11 {
+    const DEFAULT_SIZE: usize = 8;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(2);
+        let mut monkeys: Vec<Monkey> = (0..size).map(|i| random_monkey(rng, i, size)).collect();
+        while let Some(monkey_num) = first_overflow_1(&monkeys, 20) {
+            monkeys[monkey_num].operation = random_addition(rng);
+        }
+        let chunks: Vec<String> = monkeys.iter().enumerate().map(format_monkey).collect();
+        chunks.join("\n")
+    }
+}
+
+fn random_monkey(rng: &mut Rng, index: usize, count: usize) -> Monkey {
+    let mut other = || (index + 1 + rng.index(count - 1)) % count;
+    let (if_true_throw_to, if_false_throw_to) = (other(), other());
+    Monkey {
+        inspections: 0,
+        items: (0..rng.range(1..=8))
+            .map(|_| rng.range(50..=99) as u64)
+            .collect(),
+        operation: match rng.below(5) {
+            0 => Operation::Square,
+            1 | 2 => Operation::Multiply(rng.range(2..=19) as u64),
+            _ => random_addition(rng),
+        },
+        test_divisible_by: *rng.choose(&DIVISORS),
+        if_true_throw_to,
+        if_false_throw_to,
+    }
+}
+
+fn random_addition(rng: &mut Rng) -> Operation {
+    Operation::Add(rng.range(1..=8) as u64)
+}
+
+fn format_monkey((index, monkey): (usize, &Monkey)) -> String {
+    let items: Vec<String> = monkey.items.iter().map(|item| item.to_string()).collect();
+    let operation = match monkey.operation {
+        Operation::Add(x) => format!("+ {}", x),
+        Operation::Multiply(x) => format!("* {}", x),
+        Operation::Square => "* old".to_owned(),
+    };
+    format!(
+        "Monkey {}:\n  Starting items: {}\n  Operation: new = old {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+        index,
+        items.join(", "),
+        operation,
+        monkey.test_divisible_by,
+        monkey.if_true_throw_to,
+        monkey.if_false_throw_to
+    )
+}
+
+#[test]
+fn test_generate_many_monkeys() {
+    let input = Day11::generate(&mut Rng::new(1), 500);
+    let monkeys = crate::parse_input(&input).unwrap();
+    assert_eq!(monkeys.len(), 500);
+    assert!(crate::solution_1(&monkeys, 20).is_some());
+}
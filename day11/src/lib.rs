@@ -0,0 +1,263 @@
+//! Day 11: Monkey in the Middle, tracking items thrown between monkeys.
+
+pub mod gen;
+
+use aoc_common::{lines, parse::Line, Param, Params, ParseError, Solution};
+use num::integer::gcd;
+use std::iter::successors;
+
+/// A monkey's starting items and throwing rules, plus how many items it has inspected.
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    pub inspections: u64,
+    pub items: Vec<u64>,
+    pub operation: Operation,
+    pub test_divisible_by: u64,
+    pub if_true_throw_to: usize,
+    pub if_false_throw_to: usize,
+}
+
+/// How a monkey changes an item's worry level on inspection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Add(u64),
+    Multiply(u64),
+    Square,
+}
+
+impl Operation {
+    /// The new worry level, in `u128` so that no `u64` level can overflow it.
+    fn apply(&self, old: u64) -> u128 {
+        let old = u128::from(old);
+        match *self {
+            Operation::Add(x) => old + u128::from(x),
+            Operation::Multiply(x) => old * u128::from(x),
+            Operation::Square => old * old,
+        }
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input = Vec<Monkey>;
+    type Output1 = Option<u64>;
+    type Output2 = Option<u64>;
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "rounds_1",
+            default: 20,
+            max: usize::MAX as u64,
+            help: "how many rounds part 1 watches",
+        },
+        Param {
+            name: "rounds_2",
+            default: 10000,
+            max: usize::MAX as u64,
+            help: "how many rounds part 2 watches",
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input, params: &Params) -> Self::Output1 {
+        solution_1(input, params.value::<usize>("rounds_1"))
+    }
+
+    fn part_2(input: &Self::Input, params: &Params) -> Self::Output2 {
+        solution_2(input, params.value::<usize>("rounds_2"))
+    }
+}
+
+/// Reads the monkeys' notes. Throw targets must be monkeys that exist.
+pub fn parse_input(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let lines: Vec<Line> = lines(input).collect();
+    let chunks: Vec<&[Line]> = lines
+        .split(|line| line.is_empty())
+        .filter(|chunk| !chunk.is_empty())
+        .collect();
+    let monkeys = chunks
+        .iter()
+        .map(|chunk| parse_monkey(chunk))
+        .collect::<Result<Vec<Monkey>, ParseError>>()?;
+    if monkeys.len() < 2 {
+        return Err(ParseError::new(
+            lines.len().max(1),
+            1,
+            "expected at least two monkeys",
+        ));
+    }
+    for (i, monkey) in monkeys.iter().enumerate() {
+        for target in [monkey.if_true_throw_to, monkey.if_false_throw_to] {
+            if target >= monkeys.len() {
+                return Err(chunks[i][0].error(
+                    1,
+                    format!("monkey {} throws to missing monkey {}", i, target),
+                ));
+            }
+        }
+    }
+    Ok(monkeys)
+}
+
+fn parse_monkey(lines: &[Line]) -> Result<Monkey, ParseError> {
+    let field = |i: usize, prefix: &str| -> Result<(Line, &str), ParseError> {
+        let line = lines.get(i).copied().ok_or_else(|| {
+            let last = lines[lines.len() - 1];
+            ParseError::new(last.number + 1, 1, format!("expected \"{}\"", prefix))
+        })?;
+        let value = line
+            .text
+            .trim_start()
+            .strip_prefix(prefix)
+            .ok_or_else(|| line.error(1, format!("expected \"{}\"", prefix)))?;
+        Ok((line, value))
+    };
+    field(0, "Monkey ")?;
+    let (line, items) = field(1, "Starting items: ")?;
+    let items = items
+        .split(", ")
+        .map(|item| line.parse_at(item))
+        .collect::<Result<_, _>>()?;
+    let (line, operation) = field(2, "Operation: new = old ")?;
+    let operation = match operation.split(' ').collect::<Vec<&str>>().as_slice() {
+        ["*", "old"] => Operation::Square,
+        ["*", x] => Operation::Multiply(line.parse_at(x)?),
+        ["+", x] => Operation::Add(line.parse_at(x)?),
+        _ => return Err(line.error_at(operation, "unparsable operation")),
+    };
+    let (line, divisor) = field(3, "Test: divisible by ")?;
+    let test_divisible_by = line.parse_at(divisor)?;
+    if test_divisible_by == 0 {
+        return Err(line.error_at(divisor, "cannot test divisibility by 0"));
+    }
+    let (line, if_true) = field(4, "If true: throw to monkey ")?;
+    let if_true_throw_to = line.parse_at(if_true)?;
+    let (line, if_false) = field(5, "If false: throw to monkey ")?;
+    let if_false_throw_to = line.parse_at(if_false)?;
+    Ok(Monkey {
+        inspections: 0,
+        items,
+        operation,
+        test_divisible_by,
+        if_true_throw_to,
+        if_false_throw_to,
+    })
+}
+
+/// Monkey business after `rounds` rounds (20 in the puzzle), with worry divided by three
+/// after each inspection, or `None` if a worry level outgrows a `u64`.
+pub fn solution_1(monkeys: &[Monkey], rounds: usize) -> Option<u64> {
+    monkey_business(monkeys, rounds, relieve)
+}
+
+fn relieve(worry: u128) -> Option<u64> {
+    u64::try_from(worry / 3).ok()
+}
+
+/// The first monkey to push a worry level past a `u64` during part 1's `rounds` rounds.
+pub(crate) fn first_overflow_1(monkeys: &[Monkey], rounds: usize) -> Option<usize> {
+    let mut monkeys = monkeys.to_vec();
+    for _ in 0..rounds {
+        monkeys = match run_round(&monkeys, relieve) {
+            Ok(next) => next,
+            Err(monkey_num) => return Some(monkey_num),
+        };
+    }
+    None
+}
+
+/// Monkey business after `rounds` rounds (10000 in the puzzle) without relief, or `None` if
+/// the divisors' least common multiple outgrows a `u64`.
+pub fn solution_2(monkeys: &[Monkey], rounds: usize) -> Option<u64> {
+    let lcm_divisor = monkeys.iter().try_fold(1u64, |divisor, monkey| {
+        (divisor / gcd(divisor, monkey.test_divisible_by)).checked_mul(monkey.test_divisible_by)
+    })?;
+    monkey_business(monkeys, rounds, |worry| {
+        Some((worry % u128::from(lcm_divisor)) as u64)
+    })
+}
+
+/// The inspection counts of the two busiest monkeys multiplied, or `None` if that or any
+/// worry level along the way overflows.
+fn monkey_business(
+    monkeys: &[Monkey],
+    rounds: usize,
+    worry_reduction: impl Fn(u128) -> Option<u64>,
+) -> Option<u64> {
+    let mut after_each_round = successors(Some(monkeys.to_vec()), |prev_round| {
+        run_round(prev_round, &worry_reduction).ok()
+    });
+    let mut monkey_inspections: Vec<_> = after_each_round
+        .nth(rounds)?
+        .iter()
+        .map(|monkey| monkey.inspections)
+        .collect();
+    monkey_inspections.sort_by(|a, b| b.cmp(a));
+    monkey_inspections[0].checked_mul(monkey_inspections[1])
+}
+
+/// The monkeys after one round, or the number of the monkey whose worry level overflowed.
+fn run_round(
+    monkeys: &[Monkey],
+    worry_reduction: impl Fn(u128) -> Option<u64>,
+) -> Result<Vec<Monkey>, usize> {
+    let mut new_monkeys = monkeys.to_vec();
+    for monkey_num in 0..new_monkeys.len() {
+        let items = std::mem::take(&mut new_monkeys[monkey_num].items);
+        new_monkeys[monkey_num].inspections += items.len() as u64;
+        for item in items {
+            let new = new_monkeys[monkey_num].operation.apply(item);
+            let new = worry_reduction(new).ok_or(monkey_num)?;
+            let monkey_to_throw_to =
+                if new.is_multiple_of(new_monkeys[monkey_num].test_divisible_by) {
+                    new_monkeys[monkey_num].if_true_throw_to
+                } else {
+                    new_monkeys[monkey_num].if_false_throw_to
+                };
+            new_monkeys[monkey_to_throw_to].items.push(new)
+        }
+    }
+    Ok(new_monkeys)
+}
+
+#[test]
+fn test_parse_example() {
+    let input = include_str!("../example.txt");
+    let monkeys = parse_input(input).unwrap();
+    assert_eq!(monkeys.len(), 4);
+    assert_eq!(monkeys[0].items, vec![79, 98]);
+    assert_eq!(monkeys[0].operation, Operation::Multiply(19));
+    assert_eq!(monkeys[2].operation, Operation::Square);
+    assert_eq!(monkeys[3].operation, Operation::Add(3));
+    assert_eq!(monkeys[1].test_divisible_by, 19);
+    assert_eq!(monkeys[1].if_true_throw_to, 2);
+    assert_eq!(monkeys[1].if_false_throw_to, 0);
+}
+
+#[test]
+fn test_overflow() {
+    let monkey = |items: Vec<u64>, operation, test_divisible_by| Monkey {
+        inspections: 0,
+        items,
+        operation,
+        test_divisible_by,
+        if_true_throw_to: 1,
+        if_false_throw_to: 1,
+    };
+    let monkeys = vec![
+        monkey(vec![u64::MAX], Operation::Square, 2),
+        monkey(vec![], Operation::Add(0), 3),
+    ];
+    assert_eq!(solution_1(&monkeys, 1), None);
+    assert_eq!(solution_2(&monkeys, 1), Some(1));
+
+    let monkeys = vec![
+        monkey(vec![1], Operation::Add(0), u64::MAX),
+        monkey(vec![], Operation::Add(0), u64::MAX - 1),
+    ];
+    assert_eq!(solution_2(&monkeys, 1), None);
+}
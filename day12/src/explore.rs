@@ -0,0 +1,124 @@
+use aoc_common::{
+    explore::{parse_pair, Exploration, Explore},
+    grid::Pos,
+    vis::Palette,
+    Params,
+};
+
+use crate::{find_in_height_map, vis, Day12, HeightMap, PathSearch, END_VAL, START_VAL};
+
+/// The part 1 search, extending one queued path per step.
+struct SearchExploration<'a> {
+    height_map: &'a HeightMap,
+    /// `None` when the map has no start or no end to search between.
+    search: Option<PathSearch<'a>>,
+    path: Vec<Pos>,
+}
+
+impl Explore for Day12 {
+    fn explore<'a>(input: &'a Self::Input, _: &Params) -> Box<dyn Exploration + 'a> {
+        let search = find_in_height_map(input, START_VAL)
+            .zip(find_in_height_map(input, END_VAL))
+            .map(|(start, end)| PathSearch::new(input, start, end));
+        Box::new(SearchExploration {
+            height_map: input,
+            search,
+            path: Vec::new(),
+        })
+    }
+}
+
+fn describe(path: &[Pos]) -> String {
+    path.iter()
+        .map(|pos| format!("{},{}", pos.row, pos.col))
+        .collect::<Vec<_>>()
+        .join(" -> ")
+}
+
+impl Exploration for SearchExploration<'_> {
+    fn step(&mut self) -> Option<String> {
+        let search = self.search.as_mut()?;
+        self.path = search.step()?;
+        let end = self.path.last().expect("paths start with the start");
+        Some(format!(
+            "extended a path of {} steps from {},{}; {} queued, {} found",
+            self.path.len() - 1,
+            end.row,
+            end.col,
+            search.queued(),
+            search.found().len()
+        ))
+    }
+
+    fn show(&self, palette: &Palette) -> String {
+        let visited = |pos| self.search.as_ref().is_some_and(|s| s.is_visited(pos));
+        vis::render(self.height_map, &self.path, visited, palette)
+    }
+
+    fn query(&self, words: &[&str]) -> Result<String, String> {
+        match words {
+            ["height", pos] => {
+                let (row, col) = parse_pair(pos)?;
+                let height = self
+                    .height_map
+                    .get(Pos::new(row, col))
+                    .ok_or_else(|| format!("{},{} is off the map", row, col))?;
+                Ok(format!("{} ({})", vis::square(*height), height))
+            }
+            ["path"] if self.path.is_empty() => Ok("no path yet".to_owned()),
+            ["path"] => Ok(describe(&self.path)),
+            ["found"] => {
+                let found = self.search.as_ref().map_or(&[][..], |s| s.found());
+                if found.is_empty() {
+                    return Ok("no path has reached the end yet".to_owned());
+                }
+                Ok(found
+                    .iter()
+                    .map(|path| format!("{} steps: {}", path.len() - 1, describe(path)))
+                    .collect::<Vec<_>>()
+                    .join("\n"))
+            }
+            _ => Err(format!("unknown query `{}`", words.join(" "))),
+        }
+    }
+
+    fn queries(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("height ROW,COL", "the height of a square"),
+            ("path", "the path the last step extended"),
+            ("found", "every path that has reached the end"),
+        ]
+    }
+}
+
+#[test]
+fn test_explore_search() {
+    let height_map = crate::parse_input(include_str!("../example.txt")).unwrap();
+    let mut search = Day12::explore(&height_map, &Params::defaults(&[]));
+    assert_eq!(search.query(&["path"]).as_deref(), Ok("no path yet"));
+    assert_eq!(
+        search.step().as_deref(),
+        Some("extended a path of 0 steps from 0,0; 2 queued, 0 found")
+    );
+    search.step();
+    assert_eq!(search.query(&["path"]).as_deref(), Ok("0,0 -> 0,1"));
+    assert_eq!(search.query(&["height", "2,5"]).as_deref(), Ok("E (27)"));
+    assert!(search.query(&["height", "9,9"]).is_err());
+    while search.step().is_some() {}
+    assert!(search
+        .query(&["found"])
+        .unwrap()
+        .starts_with("31 steps: 0,0 -> "));
+}
+
+#[test]
+fn test_explore_without_start() {
+    let height_map = crate::parse_input("abc\n").unwrap();
+    let mut search = Day12::explore(&height_map, &Params::defaults(&[]));
+    assert_eq!(search.step(), None);
+    assert_eq!(search.show(&Palette::mono()), "abc");
+    assert_eq!(
+        search.query(&["found"]).as_deref(),
+        Ok("no path has reached the end yet")
+    );
+}
@@ -0,0 +1,34 @@
+use aoc_common::{Generate, Rng};
+
+use crate::Day12;
+
+/// `size` is the width of the map (at least 28), which is half as tall. Heights climb from `a`
+/// on the left to `z` on the right with a little noise, and the row holding `S` and `E` climbs
+/// one step at a time, so there is always a path.
+impl Generate for Day12 {
+    const DEFAULT_SIZE: usize = 80;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let width = size.max(28);
+        let height = (width / 2).max(1);
+        let path_row = rng.index(height);
+        let mut out = String::new();
+        for row in 0..height {
+            for col in 0..width {
+                let base = (col * 26 / (width - 1)).min(25) as i64;
+                let c = if row == path_row && col == 0 {
+                    'S'
+                } else if row == path_row && col == width - 1 {
+                    'E'
+                } else if row == path_row {
+                    (b'a' + base as u8) as char
+                } else {
+                    (b'a' + (base + rng.range(-1..=1)).clamp(0, 25) as u8) as char
+                };
+                out.push(c);
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
@@ -0,0 +1,178 @@
+//! Day 12: Hill Climbing Algorithm, finding the shortest climbs on a height map.
+
+pub mod explore;
+pub mod gen;
+pub mod vis;
+
+use aoc_common::{
+    grid::{Grid, Pos},
+    Params, ParseError, Solution,
+};
+use std::collections::{HashMap, VecDeque};
+
+const START_VAL: i32 = 0;
+const END_VAL: i32 = 27;
+
+/// Heights from 1 (`a`) to 26 (`z`), with the start as 0 and the end as 27.
+pub type HeightMap = Grid<i32>;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Input = HeightMap;
+    type Output1 = Option<usize>;
+    type Output2 = Option<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input, _: &Params) -> Self::Output1 {
+        solution_1(input)?.into_iter().min()
+    }
+
+    fn part_2(input: &Self::Input, _: &Params) -> Self::Output2 {
+        solution_2(input)
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<HeightMap, ParseError> {
+    HeightMap::parse(input, |char| match char {
+        'S' => Ok(START_VAL),
+        'E' => Ok(END_VAL),
+        c @ 'a'..='z' => Ok((c as i32 - 'a' as i32) + 1),
+        x => Err(format!("invalid height value `{}`", x)),
+    })
+}
+
+/// The first position with height `val`.
+pub fn find_in_height_map(height_map: &HeightMap, val: i32) -> Option<Pos> {
+    height_map.positions_of(&val).next()
+}
+
+pub fn find_all_in_height_map(height_map: &HeightMap, val: i32) -> Vec<Pos> {
+    height_map.positions_of(&val).collect()
+}
+
+/// The length of every shortest path found from the start to the end, or `None` if
+/// the map has no start or end.
+pub fn solution_1(height_map: &HeightMap) -> Option<Vec<usize>> {
+    let start_pos = find_in_height_map(height_map, START_VAL)?;
+    let end_pos = find_in_height_map(height_map, END_VAL)?;
+    let res = find_paths(height_map, &start_pos, &end_pos)
+        .iter()
+        .map(|path| path.len() - 1)
+        .collect();
+    Some(res)
+}
+
+/// The fewest steps from any lowest square to the end.
+pub fn solution_2(height_map: &HeightMap) -> Option<usize> {
+    let starting_points = find_all_in_height_map(height_map, 1);
+    let end_pos = find_in_height_map(height_map, END_VAL)?;
+
+    starting_points
+        .iter()
+        .flat_map(|start_pos| find_paths(height_map, start_pos, &end_pos))
+        .map(|path| path.len() - 1)
+        .min()
+}
+
+/// Finds shortest paths from `start` to `end`, taking at most one step up at a time.
+pub fn find_paths(height_map: &HeightMap, start: &Pos, end: &Pos) -> Vec<Vec<Pos>> {
+    let mut search = PathSearch::new(height_map, *start, *end);
+    while search.step().is_some() {}
+    search.found
+}
+
+/// The breadth-first search behind `find_paths`, advanced one queued path at a time.
+pub struct PathSearch<'a> {
+    height_map: &'a HeightMap,
+    end: Pos,
+    queue: VecDeque<Vec<Pos>>,
+    shortest_paths: HashMap<Pos, usize>,
+    found: Vec<Vec<Pos>>,
+}
+
+impl<'a> PathSearch<'a> {
+    pub fn new(height_map: &'a HeightMap, start: Pos, end: Pos) -> Self {
+        Self {
+            height_map,
+            end,
+            queue: VecDeque::from([vec![start]]),
+            shortest_paths: HashMap::new(),
+            found: Vec::new(),
+        }
+    }
+
+    /// Extends the next queued path, returning it, or `None` once the search is over.
+    pub fn step(&mut self) -> Option<Vec<Pos>> {
+        let height_map = self.height_map;
+        let current_path = self.queue.pop_front()?;
+        let current_pos = current_path.last().unwrap();
+        for neighbor in height_map
+            .neighbours4(*current_pos)
+            .filter(|adj| is_connected(height_map, current_pos, adj) && !current_path.contains(adj))
+        {
+            if let Some(shortest_path_len) = self.shortest_paths.get(&neighbor) {
+                if current_path.len() < *shortest_path_len {
+                    self.shortest_paths.insert(neighbor, current_path.len());
+                } else {
+                    continue;
+                }
+            } else {
+                self.shortest_paths.insert(neighbor, current_path.len());
+            }
+            let mut path = current_path.clone();
+            path.push(neighbor);
+            if neighbor == self.end {
+                self.found.push(path);
+            } else {
+                self.queue.push_back(path)
+            }
+        }
+        Some(current_path)
+    }
+
+    pub fn is_visited(&self, pos: Pos) -> bool {
+        self.shortest_paths.contains_key(&pos)
+    }
+
+    /// How many paths are waiting to be extended.
+    pub fn queued(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Every path that reached the end so far.
+    pub fn found(&self) -> &[Vec<Pos>] {
+        &self.found
+    }
+}
+
+fn is_connected(height_map: &HeightMap, a: &Pos, b: &Pos) -> bool {
+    let is_above_or_below = a.row.abs_diff(b.row) == 1;
+    let is_to_left_or_right = a.col.abs_diff(b.col) == 1;
+    let a_val = &height_map[*a];
+    let b_val = &height_map[*b];
+    (is_above_or_below ^ is_to_left_or_right) && (b_val - a_val) <= 1
+}
+
+#[test]
+fn test_is_connected() {
+    let input = include_str!("../example.txt");
+    let height_map = parse_input(input).unwrap();
+    assert!(is_connected(&height_map, &Pos::new(0, 0), &Pos::new(0, 1)));
+    assert!(is_connected(&height_map, &Pos::new(0, 0), &Pos::new(1, 0)));
+    assert!(!is_connected(&height_map, &Pos::new(0, 0), &Pos::new(1, 1)));
+}
+
+#[test]
+fn test_find_paths() {
+    let input = include_str!("../example.txt");
+    let height_map = parse_input(input).unwrap();
+    let start_pos = find_in_height_map(&height_map, START_VAL).unwrap();
+    let end_pos = find_in_height_map(&height_map, END_VAL).unwrap();
+    let res = find_paths(&height_map, &start_pos, &end_pos);
+    println!("{:?}", res);
+}
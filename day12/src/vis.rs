@@ -0,0 +1,108 @@
+use std::{iter, rc::Rc};
+
+use aoc_common::{
+    grid::{Grid, Pos},
+    vis::{Frame, Palette, Role, Visualize},
+    Params,
+};
+
+use crate::{find_in_height_map, Day12, HeightMap, PathSearch, END_VAL, START_VAL};
+
+/// The part 1 search after extending one path.
+pub struct SearchFrame {
+    height_map: Rc<HeightMap>,
+    path: Vec<Pos>,
+    visited: Grid<bool>,
+}
+
+impl Visualize for Day12 {
+    type Frame = SearchFrame;
+
+    fn frames<'a>(
+        input: &'a Self::Input,
+        _: &Params,
+    ) -> Box<dyn Iterator<Item = Self::Frame> + 'a> {
+        let (Some(start), Some(end)) = (
+            find_in_height_map(input, START_VAL),
+            find_in_height_map(input, END_VAL),
+        ) else {
+            return Box::new(iter::empty());
+        };
+        let height_map = Rc::new(input.clone());
+        let mut search = PathSearch::new(input, start, end);
+        Box::new(iter::from_fn(move || {
+            let path = search.step()?;
+            let visited =
+                Grid::from_fn(input.width(), input.height(), |pos| search.is_visited(pos));
+            Some(SearchFrame {
+                height_map: Rc::clone(&height_map),
+                path,
+                visited,
+            })
+        }))
+    }
+}
+
+impl Frame for SearchFrame {
+    fn render(&self, palette: &Palette) -> String {
+        render(
+            &self.height_map,
+            &self.path,
+            |pos| self.visited[pos],
+            palette,
+        )
+    }
+}
+
+/// Draws the map with `path` on it, over the squares `visited` says the search has reached.
+pub(crate) fn render(
+    height_map: &HeightMap,
+    path: &[Pos],
+    visited: impl Fn(Pos) -> bool,
+    palette: &Palette,
+) -> String {
+    height_map
+        .rows()
+        .enumerate()
+        .map(|(row, heights)| {
+            heights
+                .iter()
+                .enumerate()
+                .map(|(col, &height)| {
+                    let pos = Pos::new(row, col);
+                    let role = if height == START_VAL || height == END_VAL {
+                        Role::Highlight
+                    } else if path.contains(&pos) {
+                        Role::Active
+                    } else if visited(pos) {
+                        Role::Trail
+                    } else {
+                        Role::Empty
+                    };
+                    palette.paint(&square(height).to_string(), role)
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The letter a height is written as in the puzzle input.
+pub(crate) fn square(height: i32) -> char {
+    match height {
+        START_VAL => 'S',
+        END_VAL => 'E',
+        h => (b'a' + h as u8 - 1) as char,
+    }
+}
+
+#[test]
+fn test_search_frames() {
+    let input = include_str!("../example.txt");
+    let height_map = crate::parse_input(input).unwrap();
+    let frames: Vec<SearchFrame> = Day12::frames(&height_map, &Params::defaults(&[])).collect();
+    assert_eq!(frames[0].path.len(), 1);
+    assert_eq!(frames[0].render(&Palette::mono()), input.trim_end());
+    let last = frames.last().unwrap();
+    assert!(last.visited.cells().filter(|&&seen| seen).count() > 1);
+}
@@ -0,0 +1,28 @@
+use aoc_common::{Generate, Rng};
+
+use crate::Day13;
+
+/// `size` is the number of packet pairs.
+impl Generate for Day13 {
+    const DEFAULT_SIZE: usize = 150;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let pairs: Vec<String> = (0..size)
+            .map(|_| format!("{}\n{}\n", packet(rng, 0), packet(rng, 0)))
+            .collect();
+        pairs.join("\n")
+    }
+}
+
+fn packet(rng: &mut Rng, depth: usize) -> String {
+    let values: Vec<String> = (0..rng.range(0..=5))
+        .map(|_| {
+            if depth < 4 && rng.chance(1, 3) {
+                packet(rng, depth + 1)
+            } else {
+                rng.range(0..=10).to_string()
+            }
+        })
+        .collect();
+    format!("[{}]", values.join(","))
+}
@@ -0,0 +1,268 @@
+//! Day 13: Distress Signal, ordering nested list packets.
+
+pub mod gen;
+
+use std::cmp::Ordering;
+
+use aoc_common::{lines, parse::Line, Params, ParseError, Solution};
+use serde_json::{json, Value};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Input = Vec<(Value, Value)>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input, _: &Params) -> Self::Output1 {
+        solution_1(input)
+    }
+
+    fn part_2(input: &Self::Input, _: &Params) -> Self::Output2 {
+        solution_2(input)
+    }
+}
+
+/// Reads pairs of packets separated by blank lines. Packets are JSON lists of integers
+/// and lists.
+pub fn parse_input(input: &str) -> Result<Vec<(Value, Value)>, ParseError> {
+    let lines: Vec<Line> = lines(input).collect();
+    lines
+        .split(|line| line.is_empty())
+        .filter(|pair_lines| !pair_lines.is_empty())
+        .map(|pair_lines| match pair_lines {
+            [first, second] => Ok((parse_packet(first)?, parse_packet(second)?)),
+            _ => Err(pair_lines[0].error(
+                1,
+                format!("expected a pair of packets, found {}", pair_lines.len()),
+            )),
+        })
+        .collect()
+}
+
+fn parse_packet(line: &Line) -> Result<Value, ParseError> {
+    let packet: Value = serde_json::from_str(line.text).map_err(|e| {
+        // serde_json appends its own position, which is relative to this line only.
+        let message = e.to_string();
+        let message = message.split(" at line ").next().unwrap_or_default();
+        line.error(e.column(), format!("invalid packet: {}", message))
+    })?;
+    if !packet.is_array() {
+        return Err(line.error(1, "a packet must be a list"));
+    }
+    validate_packet_values(line, &packet)?;
+    Ok(packet)
+}
+
+fn validate_packet_values(line: &Line, value: &Value) -> Result<(), ParseError> {
+    match value {
+        Value::Number(n) if n.is_i64() => Ok(()),
+        Value::Array(values) => values
+            .iter()
+            .try_for_each(|value| validate_packet_values(line, value)),
+        other => Err(line.error(
+            1,
+            format!(
+                "packets may only contain integers and lists, found {}",
+                other
+            ),
+        )),
+    }
+}
+
+/// Sums the 1-based indices of the pairs already in the right order.
+pub fn solution_1(input: &[(Value, Value)]) -> usize {
+    (1..)
+        .zip(input.iter())
+        .filter(|(_, (left, right))| compare_packets(left, right) == Ordering::Less)
+        .map(|(i, _)| i)
+        .sum()
+}
+
+/// Multiplies the positions of the divider packets `[[2]]` and `[[6]]` once all
+/// packets are sorted.
+pub fn solution_2(input: &[(Value, Value)]) -> usize {
+    let mut all_packets = Vec::new();
+    for (left, right) in input {
+        all_packets.push(left);
+        all_packets.push(right);
+    }
+    let divider_1 = json!([[2]]);
+    let divider_2 = json!([[6]]);
+    all_packets.push(&divider_1);
+    all_packets.push(&divider_2);
+    all_packets.sort_by(|a, b| compare_packets(a, b));
+
+    let divider_1_idx = all_packets
+        .iter()
+        .position(|item| *item == &divider_1)
+        .unwrap()
+        + 1;
+    let divider_2_idx = all_packets
+        .iter()
+        .position(|item| *item == &divider_2)
+        .unwrap()
+        + 1;
+    divider_1_idx * divider_2_idx
+}
+
+/// Orders two packets, comparing an integer against a list as a one-item list.
+pub fn compare_packets(left: &Value, right: &Value) -> Ordering {
+    use Value::*;
+    let mut left_iter = left.as_array().unwrap().iter();
+    let mut right_iter = right.as_array().unwrap().iter();
+    loop {
+        let x = left_iter.next();
+        let y = right_iter.next();
+        if x.is_none() && y.is_some() {
+            return Ordering::Less;
+        }
+        if x.is_some() && y.is_none() {
+            return Ordering::Greater;
+        }
+        let (Some(x), Some(y)) = (x, y) else {
+            return Ordering::Equal;
+        };
+        match (x, y) {
+            (Number(x), Number(y)) => {
+                let x = x.as_i64();
+                let y = y.as_i64();
+                if x < y {
+                    return Ordering::Less;
+                } else if x > y {
+                    return Ordering::Greater;
+                }
+            }
+            (Array(_), Array(_)) => {
+                let order = compare_packets(x, y);
+                if order == Ordering::Equal {
+                    continue;
+                } else {
+                    return order;
+                }
+            }
+            (Array(_), Number(y)) => {
+                let order = compare_packets(x, &Array(vec![Number(y.clone())]));
+                if order == Ordering::Equal {
+                    continue;
+                } else {
+                    return order;
+                }
+            }
+            (Number(x), Array(_)) => {
+                let order = compare_packets(&Array(vec![Number(x.clone())]), y);
+                if order == Ordering::Equal {
+                    continue;
+                } else {
+                    return order;
+                }
+            }
+            _ => unreachable!("validated by parse_packet"),
+        };
+    }
+}
+
+#[test]
+fn test_compare_packets_example() {
+    let example = include_str!("../example.txt");
+    let pairs = parse_input(example).unwrap();
+    assert_eq!(pairs.len(), 8);
+    let (left, right) = &pairs[0];
+    assert_eq!(compare_packets(left, right), Ordering::Less);
+    let (left, right) = &pairs[1];
+    assert_eq!(compare_packets(left, right), Ordering::Less);
+    let (left, right) = &pairs[2];
+    assert_eq!(compare_packets(left, right), Ordering::Greater);
+    let (left, right) = &pairs[3];
+    assert_eq!(compare_packets(left, right), Ordering::Less);
+    let (left, right) = &pairs[4];
+    assert_eq!(compare_packets(left, right), Ordering::Greater);
+    let (left, right) = &pairs[5];
+    assert_eq!(compare_packets(left, right), Ordering::Less);
+    let (left, right) = &pairs[6];
+    assert_eq!(compare_packets(left, right), Ordering::Greater);
+    let (left, right) = &pairs[7];
+    assert_eq!(compare_packets(left, right), Ordering::Greater);
+}
+
+#[cfg(test)]
+use proptest::prelude::*;
+
+/// An independent ordering on packets, built from the standard lexicographic comparison.
+#[cfg(test)]
+fn reference_compare(left: &Value, right: &Value) -> Ordering {
+    match (left, right) {
+        (Value::Number(x), Value::Number(y)) => x.as_i64().cmp(&y.as_i64()),
+        (Value::Array(xs), Value::Array(ys)) => {
+            let mut pairs = xs.iter().zip(ys);
+            pairs
+                .find_map(|(x, y)| Some(reference_compare(x, y)).filter(|o| o.is_ne()))
+                .unwrap_or_else(|| xs.len().cmp(&ys.len()))
+        }
+        (Value::Number(_), Value::Array(_)) => reference_compare(&json!([left]), right),
+        _ => reference_compare(left, &json!([right])),
+    }
+}
+
+/// Part 2 without sorting: a divider's index is one more than the packets before it.
+#[cfg(test)]
+fn reference_solution_2(input: &[(Value, Value)]) -> usize {
+    let packets: Vec<&Value> = input.iter().flat_map(|(l, r)| [l, r]).collect();
+    let before = |divider: &Value| {
+        packets
+            .iter()
+            .filter(|packet| reference_compare(packet, divider).is_lt())
+            .count()
+    };
+    (before(&json!([[2]])) + 1) * (before(&json!([[6]])) + 2)
+}
+
+#[cfg(test)]
+fn packet_strategy() -> impl Strategy<Value = Value> {
+    let leaf = (0..=10i64).prop_map(Value::from);
+    let value = leaf.prop_recursive(4, 24, 4, |inner| {
+        prop::collection::vec(inner, 0..4).prop_map(Value::Array)
+    });
+    prop::collection::vec(value, 0..4).prop_map(Value::Array)
+}
+
+#[cfg(test)]
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(2000))]
+    #[test]
+    fn test_compare_matches_reference(left in packet_strategy(), right in packet_strategy()) {
+        prop_assert_eq!(compare_packets(&left, &right), reference_compare(&left, &right));
+    }
+
+    #[test]
+    fn test_solutions_match_reference(
+        pairs in prop::collection::vec((packet_strategy(), packet_strategy()), 1..8)
+    ) {
+        let text = pairs
+            .iter()
+            .map(|(left, right)| format!("{}\n{}\n", left, right))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let input = parse_input(&text).unwrap();
+        prop_assert_eq!(&input, &pairs);
+        // Where a packet equal to a divider sorts is up to the sort, so the puzzle has no answer.
+        let dividers = [json!([[2]]), json!([[6]])];
+        prop_assume!(input.iter().flat_map(|(l, r)| [l, r]).all(|packet| {
+            dividers
+                .iter()
+                .all(|divider| reference_compare(packet, divider).is_ne())
+        }));
+        let expected_1: usize = (1..)
+            .zip(&input)
+            .filter(|(_, (left, right))| reference_compare(left, right).is_lt())
+            .map(|(i, _)| i)
+            .sum();
+        prop_assert_eq!(solution_1(&input), expected_1);
+        prop_assert_eq!(solution_2(&input), reference_solution_2(&input));
+    }
+}
@@ -0,0 +1,98 @@
+use aoc_common::{
+    explore::{parse_pair, Exploration, Explore},
+    grid::Pos,
+    vis::Palette,
+    Params,
+};
+
+use crate::{vis, Cave, Cell, Day14};
+
+/// The part 1 cave, filling up one grain of sand per step.
+struct CaveExploration {
+    cave: Cave,
+    grains: usize,
+}
+
+impl Explore for Day14 {
+    fn explore<'a>(input: &'a Self::Input, params: &Params) -> Box<dyn Exploration + 'a> {
+        Box::new(CaveExploration {
+            cave: Cave::from_rock_paths(input, params.value::<usize>("spout")),
+            grains: 0,
+        })
+    }
+}
+
+impl Exploration for CaveExploration {
+    fn step(&mut self) -> Option<String> {
+        let pos = self.cave.rest_pos()?;
+        self.cave.grid[pos] = Cell::Sand;
+        self.grains += 1;
+        Some(format!(
+            "grain {} rests at {},{}",
+            self.grains,
+            pos.col + self.cave.min_col,
+            pos.row
+        ))
+    }
+
+    fn show(&self, palette: &Palette) -> String {
+        vis::render(&self.cave, palette)
+    }
+
+    fn query(&self, words: &[&str]) -> Result<String, String> {
+        match words {
+            ["grains"] => Ok(self.grains.to_string()),
+            ["at", point] => {
+                let (col, depth) = parse_pair(point)?;
+                let cell = col
+                    .checked_sub(self.cave.min_col)
+                    .and_then(|col| self.cave.grid.get(Pos::new(depth, col)));
+                Ok(match cell {
+                    Some(Cell::SandSpout) => "the spout",
+                    Some(Cell::Empty) | None => "air",
+                    Some(Cell::Rock) => "rock",
+                    Some(Cell::Sand) => "sand",
+                }
+                .to_owned())
+            }
+            ["bounds"] => Ok(format!(
+                "columns {} to {}, depths 0 to {}",
+                self.cave.min_col,
+                self.cave.min_col + self.cave.grid.width() - 1,
+                self.cave.grid.height() - 1
+            )),
+            _ => Err(format!("unknown query `{}`", words.join(" "))),
+        }
+    }
+
+    fn queries(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("grains", "how many grains of sand have come to rest"),
+            ("at COL,DEPTH", "what is at a point"),
+            ("bounds", "the columns and depths the cave covers"),
+        ]
+    }
+}
+
+#[test]
+fn test_explore_cave() {
+    use aoc_common::Solution;
+
+    let rock_paths = crate::parse_input(include_str!("../example.txt")).unwrap();
+    let mut cave = Day14::explore(&rock_paths, &Params::defaults(Day14::PARAMS));
+    assert_eq!(cave.step().as_deref(), Some("grain 1 rests at 500,8"));
+    assert_eq!(cave.query(&["at", "500,8"]).as_deref(), Ok("sand"));
+    assert_eq!(cave.query(&["at", "498,4"]).as_deref(), Ok("rock"));
+    assert_eq!(cave.query(&["at", "0,0"]).as_deref(), Ok("air"));
+    while cave.step().is_some() {}
+    assert_eq!(cave.query(&["grains"]).as_deref(), Ok("24"));
+    assert_eq!(
+        cave.query(&["bounds"]).as_deref(),
+        Ok("columns 494 to 503, depths 0 to 9")
+    );
+    assert!(cave.query(&["at"]).is_err());
+    assert_eq!(
+        cave.show(&Palette::mono()).lines().next(),
+        Some("......+...")
+    );
+}
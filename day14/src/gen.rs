@@ -0,0 +1,49 @@
+use aoc_common::{Generate, Rng};
+
+use crate::Day14;
+
+/// `size` is the number of rock paths. Rocks stay within 100 columns of the sand spout at
+/// `500,0` and never deeper than 160, and always span the spout's column.
+impl Generate for Day14 {
+    const DEFAULT_SIZE: usize = 150;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut paths = Vec::new();
+        let (mut min_col, mut max_col) = (i64::MAX, i64::MIN);
+        for _ in 0..size {
+            let mut col = rng.range(440..=560);
+            let mut depth = rng.range(2..=160);
+            let mut points = vec![(col, depth)];
+            let mut horizontal = rng.chance(1, 2);
+            for _ in 0..rng.range(1..=5) {
+                let step = rng.range(-8..=8);
+                if horizontal {
+                    col = (col + step).clamp(400, 600);
+                } else {
+                    depth = (depth + step).clamp(2, 160);
+                }
+                horizontal = !horizontal;
+                points.push((col, depth));
+            }
+            for &(col, _) in points.iter() {
+                min_col = min_col.min(col);
+                max_col = max_col.max(col);
+            }
+            paths.push(points);
+        }
+        if !(min_col <= 500 && 500 <= max_col) {
+            let depth = rng.range(2..=160);
+            paths.push(vec![(500, depth), (500, depth + 1)]);
+        }
+        paths
+            .iter()
+            .map(|points| {
+                let points: Vec<String> = points
+                    .iter()
+                    .map(|(col, depth)| format!("{},{}", col, depth))
+                    .collect();
+                points.join(" -> ") + "\n"
+            })
+            .collect()
+    }
+}
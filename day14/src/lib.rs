@@ -0,0 +1,443 @@
+//! Day 14: Regolith Reservoir, pouring sand into a cave of rock paths.
+
+pub mod explore;
+pub mod gen;
+pub mod vis;
+
+use std::{fmt::Display, iter::successors, vec};
+
+use aoc_common::{
+    grid::{Direction, Grid, Pos},
+    lines, Param, Params, ParseError, Solution,
+};
+
+/// Where sand pours in for the real puzzle, at depth 0.
+const SAND_SPOUT_COL: usize = 500;
+
+/// The largest column or depth a rock may have. The puzzle's caves lie within a hundred or so
+/// columns of the spout at 500 and are under 200 deep. Part 2's floor lets sand spread as wide
+/// as the cave is deep, so the grid grows with the square of the depth; 1000 leaves room for
+/// caves several times bigger than the puzzle's while keeping the grid to a few million cells.
+pub const MAX_COORD: usize = 1000;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Input = Vec<Path>;
+    type Output1 = usize;
+    type Output2 = usize;
+    const PARAMS: &'static [Param] = &[Param {
+        name: "spout",
+        default: SAND_SPOUT_COL as u64,
+        max: MAX_COORD as u64,
+        help: "the column sand pours in from",
+    }];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input, params: &Params) -> Self::Output1 {
+        solution_1(input, params.value::<usize>("spout"))
+    }
+
+    fn part_2(input: &Self::Input, params: &Params) -> Self::Output2 {
+        solution_2(input, params.value::<usize>("spout"))
+    }
+}
+
+/// Counts the grains that come to rest before sand starts falling into the abyss.
+pub fn solution_1(rock_paths: &[Path], spout_col: usize) -> usize {
+    let mut cave = Cave::from_rock_paths(rock_paths, spout_col);
+    cave.fill_with_sand();
+    cave.count_grains_of_sand()
+}
+
+/// Counts the grains that come to rest on the floor before the spout is blocked.
+pub fn solution_2(rock_paths: &[Path], spout_col: usize) -> usize {
+    let (_, (_, max_depth)) = bounds(rock_paths, spout_col);
+    let floor = max_depth + 2;
+    // Sand piles up in a triangle below the spout, so the floor only needs to be that wide.
+    // Everything moves right by `floor` columns so that the triangle cannot pass column 0.
+    let mut rock_paths: Vec<Path> = rock_paths
+        .iter()
+        .map(|path| {
+            path.iter()
+                .map(|point| Point {
+                    depth: point.depth,
+                    col: point.col + floor,
+                })
+                .collect()
+        })
+        .collect();
+    rock_paths.push(vec![
+        Point {
+            depth: floor,
+            col: spout_col,
+        },
+        Point {
+            depth: floor,
+            col: spout_col + 2 * floor,
+        },
+    ]);
+    let mut cave = Cave::from_rock_paths(&rock_paths, spout_col + floor);
+    cave.fill_with_sand();
+    cave.count_grains_of_sand()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Point {
+    pub depth: usize,
+    pub col: usize,
+}
+
+/// A rock path, in straight lines from point to point.
+pub type Path = Vec<Point>;
+
+/// Reads lines like `498,4 -> 498,6 -> 496,6`.
+pub fn parse_input(input: &str) -> Result<Vec<Path>, ParseError> {
+    let paths = lines(input)
+        .map(|line| {
+            let mut path: Path = Vec::new();
+            for coord in line.text.split(" -> ") {
+                let (col, depth) = coord
+                    .split_once(',')
+                    .ok_or_else(|| line.error_at(coord, format!("invalid point `{}`", coord)))?;
+                let point = Point {
+                    depth: line.parse_at(depth)?,
+                    col: line.parse_at(col)?,
+                };
+                if point.col > MAX_COORD || point.depth > MAX_COORD {
+                    return Err(
+                        line.error_at(coord, format!("coordinates must be at most {}", MAX_COORD))
+                    );
+                }
+                if let Some(prev) = path.last() {
+                    if prev.col != point.col && prev.depth != point.depth {
+                        return Err(line.error_at(coord, "rock paths must be straight lines"));
+                    }
+                }
+                path.push(point);
+            }
+            Ok(path)
+        })
+        .collect::<Result<Vec<Path>, ParseError>>()?;
+    if paths.is_empty() {
+        return Err(ParseError::new(1, 1, "expected at least one rock path"));
+    }
+    Ok(paths)
+}
+
+/// The columns and depths covered by the rocks and the sand spout.
+fn bounds(rock_paths: &[Path], spout_col: usize) -> ((usize, usize), (usize, usize)) {
+    let min_col = rock_paths
+        .iter()
+        .flatten()
+        .map(|point| point.col)
+        .chain([spout_col])
+        .min()
+        .unwrap();
+    let max_col = rock_paths
+        .iter()
+        .flatten()
+        .map(|point| point.col)
+        .chain([spout_col])
+        .max()
+        .unwrap();
+    let max_depth = rock_paths
+        .iter()
+        .flatten()
+        .map(|point| point.depth)
+        .max()
+        .unwrap();
+    ((min_col, max_col), (0, max_depth))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    SandSpout,
+    Empty,
+    Rock,
+    Sand,
+}
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match self {
+            Cell::SandSpout => '+',
+            Cell::Empty => '.',
+            Cell::Rock => '#',
+            Cell::Sand => 'o',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+enum Fall {
+    To(Pos),
+    Out,
+    Rest,
+}
+
+#[derive(Debug, Clone)]
+struct Cave {
+    min_col: usize,
+    spout_col: usize,
+    grid: Grid<Cell>,
+}
+
+impl Cave {
+    fn from_rock_paths(rock_paths: &[Path], spout_col: usize) -> Self {
+        let ((min_col, max_col), (min_depth, max_depth)) = bounds(rock_paths, spout_col);
+        let mut grid = Grid::new(
+            max_col - min_col + 1,
+            max_depth - min_depth + 1,
+            Cell::Empty,
+        );
+        for path in rock_paths {
+            for window in path.windows(2) {
+                let a = &window[0];
+                let b = &window[1];
+                if a.col == b.col {
+                    let (min, max) = if a.depth < b.depth {
+                        (a.depth, b.depth)
+                    } else {
+                        (b.depth, a.depth)
+                    };
+                    for depth in min..=max {
+                        grid[Pos::new(depth, a.col - min_col)] = Cell::Rock;
+                    }
+                } else if a.depth == b.depth {
+                    let (min, max) = if a.col < b.col {
+                        (a.col, b.col)
+                    } else {
+                        (b.col, a.col)
+                    };
+                    for col in min..=max {
+                        grid[Pos::new(a.depth, col - min_col)] = Cell::Rock;
+                    }
+                } else {
+                    unreachable!("parse_input only accepts straight rock paths")
+                }
+            }
+        }
+        grid[Pos::new(0, spout_col - min_col)] = Cell::SandSpout;
+        Self {
+            min_col,
+            spout_col,
+            grid,
+        }
+    }
+
+    fn sand_spout_pos(&self) -> Pos {
+        Pos::new(0, self.spout_col - self.min_col)
+    }
+
+    /// Where a grain of sand at `pos` goes next.
+    fn fall(&self, pos: Pos) -> Fall {
+        for direction in [Direction::Down, Direction::DownLeft, Direction::DownRight] {
+            // Anything falling off the edge of the cave falls forever.
+            let Some(next) = pos.step(direction) else {
+                return Fall::Out;
+            };
+            match self.grid.get(next) {
+                None => return Fall::Out,
+                Some(Cell::Empty) => return Fall::To(next),
+                Some(_) => {}
+            }
+        }
+        Fall::Rest
+    }
+
+    /// Where the next grain of sand comes to rest, if it does.
+    fn rest_pos(&self) -> Option<Pos> {
+        let mut current_pos = self.sand_spout_pos();
+        if self.grid[current_pos] == Cell::Sand {
+            return None;
+        }
+        loop {
+            match self.fall(current_pos) {
+                Fall::To(next) => current_pos = next,
+                Fall::Out => return None,
+                Fall::Rest => return Some(current_pos),
+            }
+        }
+    }
+
+    fn drop_grain_of_sand(&self) -> Option<Cave> {
+        let pos = self.rest_pos()?;
+        let mut next_cave = self.clone();
+        next_cave.grid[pos] = Cell::Sand;
+        Some(next_cave)
+    }
+
+    /// Pours sand in place until a grain falls out or the spout is blocked. Each grain
+    /// follows the path of the one before it, so it starts from where that one came to rest.
+    fn fill_with_sand(&mut self) {
+        let mut path = vec![self.sand_spout_pos()];
+        while let Some(&pos) = path.last() {
+            match self.fall(pos) {
+                Fall::To(next) => path.push(next),
+                Fall::Out => return,
+                Fall::Rest => {
+                    self.grid[pos] = Cell::Sand;
+                    path.pop();
+                }
+            }
+        }
+    }
+
+    fn drop_sand_until_all_settles(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(successors(Some(self.clone()), |current_cave| {
+            current_cave.drop_grain_of_sand()
+        }))
+    }
+    fn count_grains_of_sand(&self) -> usize {
+        self.grid
+            .cells()
+            .filter(|&&cell| cell == Cell::Sand)
+            .count()
+    }
+}
+
+impl Display for Cave {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", self.grid)
+    }
+}
+
+#[cfg(test)]
+use proptest::prelude::*;
+
+/// Blocked points as `(col, depth)`, drawn straight from the paths.
+#[cfg(test)]
+fn reference_rocks(rock_paths: &[Path]) -> std::collections::HashSet<(usize, usize)> {
+    let mut rocks = std::collections::HashSet::new();
+    for path in rock_paths {
+        for window in path.windows(2) {
+            let (a, b) = (&window[0], &window[1]);
+            for col in a.col.min(b.col)..=a.col.max(b.col) {
+                for depth in a.depth.min(b.depth)..=a.depth.max(b.depth) {
+                    rocks.insert((col, depth));
+                }
+            }
+        }
+    }
+    rocks
+}
+
+/// Drops grains one at a time onto a set of blocked points until one falls past the rocks.
+#[cfg(test)]
+fn reference_solution_1(rock_paths: &[Path]) -> usize {
+    let mut blocked = reference_rocks(rock_paths);
+    let max_depth = blocked.iter().map(|&(_, depth)| depth).max().unwrap();
+    let mut grains = 0;
+    while !blocked.contains(&(SAND_SPOUT_COL, 0)) {
+        let (mut col, mut depth) = (SAND_SPOUT_COL, 0);
+        loop {
+            if depth > max_depth {
+                return grains;
+            }
+            match [col, col - 1, col + 1]
+                .into_iter()
+                .find(|&next| !blocked.contains(&(next, depth + 1)))
+            {
+                Some(next) => (col, depth) = (next, depth + 1),
+                None => break,
+            }
+        }
+        blocked.insert((col, depth));
+        grains += 1;
+    }
+    grains
+}
+
+/// With a floor, sand fills every point it can reach, so count those breadth first.
+#[cfg(test)]
+fn reference_solution_2(rock_paths: &[Path]) -> usize {
+    let rocks = reference_rocks(rock_paths);
+    let floor = rocks.iter().map(|&(_, depth)| depth).max().unwrap() + 2;
+    let mut filled = std::collections::HashSet::from([(SAND_SPOUT_COL, 0)]);
+    let mut layer = vec![(SAND_SPOUT_COL, 0)];
+    for depth in 1..floor {
+        layer = layer
+            .iter()
+            .flat_map(|&(col, _)| [col - 1, col, col + 1])
+            .map(|col| (col, depth))
+            .filter(|point| !rocks.contains(point) && filled.insert(*point))
+            .collect();
+    }
+    filled.len()
+}
+
+#[cfg(test)]
+fn rock_path_strategy() -> impl Strategy<Value = Path> {
+    let start = (494..=506usize, 1..=10usize);
+    let turns = prop::collection::vec((any::<bool>(), -4..=4i32), 1..4);
+    (start, turns).prop_map(|((col, depth), turns)| {
+        let mut path = vec![Point { depth, col }];
+        for (horizontal, delta) in turns {
+            let last = path.last().unwrap();
+            let moved = |value: usize| value.saturating_add_signed(delta as isize).max(1);
+            path.push(match horizontal {
+                true => Point {
+                    depth: last.depth,
+                    col: moved(last.col),
+                },
+                false => Point {
+                    depth: moved(last.depth),
+                    col: last.col,
+                },
+            });
+        }
+        path
+    })
+}
+
+#[cfg(test)]
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(2000))]
+    #[test]
+    fn test_solutions_match_reference(
+        rock_paths in prop::collection::vec(rock_path_strategy(), 1..5)
+    ) {
+        let text: String = rock_paths
+            .iter()
+            .map(|path| {
+                let points: Vec<String> = path
+                    .iter()
+                    .map(|point| format!("{},{}", point.col, point.depth))
+                    .collect();
+                points.join(" -> ") + "\n"
+            })
+            .collect();
+        let input = parse_input(&text).unwrap();
+        prop_assert_eq!(&input, &rock_paths);
+        prop_assert_eq!(solution_1(&input, SAND_SPOUT_COL), reference_solution_1(&input));
+        prop_assert_eq!(solution_2(&input, SAND_SPOUT_COL), reference_solution_2(&input));
+    }
+}
+
+#[test]
+fn test_deep_cave() {
+    let rock_paths = parse_input(&format!("500,{0} -> 501,{0}\n", MAX_COORD)).unwrap();
+    assert_eq!(solution_1(&rock_paths, 500), 0);
+    assert_eq!(solution_2(&rock_paths, 500), 1004002);
+    assert_eq!(
+        parse_input("500,1001\n").unwrap_err(),
+        ParseError::new(1, 1, format!("coordinates must be at most {}", MAX_COORD))
+    );
+}
+
+#[test]
+fn test_spout_param() {
+    let mut params = Params::defaults(Day14::PARAMS);
+    assert_eq!(
+        params.assign("spout=1001"),
+        Err("1001 is too big for parameter `spout`, which is at most 1000".to_owned())
+    );
+    params.assign("spout=1000").unwrap();
+    let rock_paths = parse_input(include_str!("../example.txt")).unwrap();
+    assert_eq!(Day14::part_1(&rock_paths, &params), 0);
+}
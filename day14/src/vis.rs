@@ -0,0 +1,63 @@
+use aoc_common::{
+    vis::{Frame, Palette, Role, Visualize},
+    Params,
+};
+
+use crate::{Cave, Cell, Day14};
+
+/// The part 1 cave after each grain of sand comes to rest.
+pub struct CaveFrame(Cave);
+
+impl Visualize for Day14 {
+    type Frame = CaveFrame;
+
+    fn frames<'a>(
+        input: &'a Self::Input,
+        params: &Params,
+    ) -> Box<dyn Iterator<Item = Self::Frame> + 'a> {
+        Box::new(
+            Cave::from_rock_paths(input, params.value::<usize>("spout"))
+                .drop_sand_until_all_settles()
+                .map(CaveFrame),
+        )
+    }
+}
+
+impl Frame for CaveFrame {
+    fn render(&self, palette: &Palette) -> String {
+        render(&self.0, palette)
+    }
+}
+
+pub(crate) fn render(cave: &Cave, palette: &Palette) -> String {
+    cave.grid
+        .rows()
+        .map(|row| {
+            row.iter()
+                .map(|cell| {
+                    let role = match cell {
+                        Cell::SandSpout => Role::Highlight,
+                        Cell::Empty => Role::Empty,
+                        Cell::Rock => Role::Wall,
+                        Cell::Sand => Role::Active,
+                    };
+                    palette.paint(&cell.to_string(), role)
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn test_cave_frames() {
+    use aoc_common::Solution;
+
+    let rock_paths = crate::parse_input(include_str!("../example.txt")).unwrap();
+    let params = Params::defaults(Day14::PARAMS);
+    let frames: Vec<CaveFrame> = Day14::frames(&rock_paths, &params).collect();
+    assert_eq!(frames.len(), 25);
+    let last = frames.last().unwrap().render(&Palette::mono());
+    assert_eq!(last, frames.last().unwrap().0.grid.to_string());
+    assert_eq!(last.matches('o').count(), 24);
+}
@@ -0,0 +1,81 @@
+use aoc_common::{Generate, Rng};
+
+use crate::Day15;
+
+/// The largest x and y the distress beacon can have in the real puzzle.
+const MAX: i64 = 4000000;
+
+/// `size` is the number of sensors (at least four). They cover every position with both
+/// coordinates in `0..=4000000` but one, the distress beacon, so part 2 has a single answer.
+///
+/// Most sensors sit on a square lattice spanning that area, each seeing at least two lattice
+/// spacings but stopping short of the distress beacon. Any other position has a lattice point
+/// within two spacings of it on the side away from the distress beacon, which is closer to it
+/// than to the beacon and so covers it. The rest of the sensors are scattered at random, also
+/// stopping short of the distress beacon.
+impl Generate for Day15 {
+    const DEFAULT_SIZE: usize = 25;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_within(rng, size, MAX)
+    }
+}
+
+/// Like [`Day15::generate`], but leaving the one gap in `0..=max` instead.
+fn generate_within(rng: &mut Rng, size: usize, max: i64) -> String {
+    let size = size.max(4);
+    let side = size.isqrt().min(max as usize / 5) as i64;
+    let spacing = (max + side - 2) / (side - 1);
+    // Inside a lattice cell and off its lines, so that every lattice sensor is at least
+    // four away and sees at least three.
+    let mut coordinate = || rng.range(0..=max / spacing - 1) * spacing + rng.range(2..=spacing - 2);
+    let distress = (coordinate(), coordinate());
+    let mut sensors = Vec::new();
+    for i in 0..side {
+        for j in 0..side {
+            let reach = rng.range(2 * spacing..=3 * spacing);
+            sensors.push(((i * spacing, j * spacing), reach));
+        }
+    }
+    for _ in sensors.len()..size {
+        let mut position = (rng.range(0..=max), rng.range(0..=max));
+        if distance(position, distress) < 2 {
+            position.0 = distress.0 + 2;
+        }
+        sensors.push((position, rng.range(1..=spacing)));
+    }
+    rng.shuffle(&mut sensors);
+    sensors
+        .into_iter()
+        .map(|((x, y), reach)| {
+            let reach = reach.min(distance((x, y), distress) - 1);
+            let dx = rng.range(-reach..=reach);
+            let dy = (reach - dx.abs()) * if rng.chance(1, 2) { 1 } else { -1 };
+            format!(
+                "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+                x,
+                y,
+                x + dx,
+                y + dy
+            )
+        })
+        .collect()
+}
+
+fn distance((x1, y1): (i64, i64), (x2, y2): (i64, i64)) -> i64 {
+    (x1 - x2).abs() + (y1 - y2).abs()
+}
+
+#[test]
+fn test_one_uncovered_position() {
+    use crate::{parse_input, Coverage};
+
+    for (seed, size) in [(1, 0), (2, 30), (3, 500)] {
+        let input = generate_within(&mut Rng::new(seed), size, 400);
+        assert_eq!(input.lines().count(), size.max(4));
+        let coverage = Coverage::new(parse_input(&input).unwrap());
+        assert_eq!(crate::solution_2(&coverage, 400).count(), 1);
+    }
+    let input = Day15::generate(&mut Rng::new(4), 100);
+    assert_eq!(parse_input(&input).unwrap().len(), 100);
+}
@@ -0,0 +1,335 @@
+//! Day 15: Beacon Exclusion Zone, working out where sensors rule out beacons.
+
+pub mod gen;
+
+use std::collections::HashSet;
+
+use aoc_common::{answer::Diagnosed, lines, position::Point, Param, Params, ParseError, Solution};
+use regex::Regex;
+
+#[derive(Debug, Clone)]
+pub struct Sensor {
+    pub position: Point,
+    pub closest_beacon: Point,
+}
+
+impl Sensor {
+    fn manhatan_distance_to_beacon(&self) -> i32 {
+        self.position.manhattan_distance(&self.closest_beacon)
+    }
+
+    #[allow(dead_code)]
+    fn perimiter(&self) -> Vec<Point> {
+        let mut res = Vec::new();
+        let distance_to_beacon = self.manhatan_distance_to_beacon();
+        let mut current_position = Point {
+            x: self.position.x,
+            y: self.position.y + distance_to_beacon,
+        };
+        for _ in 0..distance_to_beacon {
+            current_position.x -= 1;
+            current_position.y -= 1;
+            res.push(current_position);
+        }
+        for _ in 0..distance_to_beacon {
+            current_position.x += 1;
+            current_position.y -= 1;
+            res.push(current_position);
+        }
+        for _ in 0..distance_to_beacon {
+            current_position.x += 1;
+            current_position.y += 1;
+            res.push(current_position);
+        }
+        for _ in 0..distance_to_beacon {
+            current_position.x -= 1;
+            current_position.y += 1;
+            res.push(current_position);
+        }
+        res
+    }
+
+    /// How far the sensor sees, in `i64` so that no coordinates can overflow it.
+    fn radius(&self) -> i64 {
+        let dx = i64::from(self.closest_beacon.x) - i64::from(self.position.x);
+        let dy = i64::from(self.closest_beacon.y) - i64::from(self.position.y);
+        dx.abs() + dy.abs()
+    }
+
+    /// The columns the sensor covers on `row`, if it reaches that far.
+    fn range_on_row(&self, row: i32) -> Option<(i64, i64)> {
+        let reach = self.radius() - (i64::from(row) - i64::from(self.position.y)).abs();
+        let x = i64::from(self.position.x);
+        (reach >= 0).then_some((x - reach, x + reach))
+    }
+}
+
+/// The sensors and their beacons, from which the columns covered on any row are worked out.
+#[derive(Debug, Default)]
+pub struct Coverage {
+    sensors: Vec<Sensor>,
+    beacons: HashSet<Point>,
+}
+
+impl Coverage {
+    pub fn new(sensors: Vec<Sensor>) -> Self {
+        let beacons = sensors.iter().map(|sensor| sensor.closest_beacon).collect();
+        Coverage { sensors, beacons }
+    }
+
+    /// The ranges of columns covered on `row`, merged and sorted.
+    pub fn row(&self, row: i32) -> Vec<(i64, i64)> {
+        let mut ranges: Vec<(i64, i64)> = self
+            .sensors
+            .iter()
+            .filter_map(|sensor| sensor.range_on_row(row))
+            .collect();
+        ranges.sort_unstable();
+        ranges
+            .into_iter()
+            .fold(Vec::new(), |mut merged, (start, end)| {
+                match merged.last_mut() {
+                    Some(last) if last.1 + 1 >= start => last.1 = last.1.max(end),
+                    _ => merged.push((start, end)),
+                }
+                merged
+            })
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    type Input = Coverage;
+    type Output1 = usize;
+    type Output2 = Diagnosed<Option<i128>>;
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "row",
+            default: 2000000,
+            max: i32::MAX as u64,
+            help: "the row part 1 counts positions without a beacon in",
+        },
+        Param {
+            name: "max",
+            default: 4000000,
+            max: i32::MAX as u64,
+            help: "the largest x and y the distress beacon can be at",
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Coverage::new(parse_input(input)?))
+    }
+
+    fn part_1(input: &Self::Input, params: &Params) -> Self::Output1 {
+        solution_1(input, params.value::<i32>("row"))
+    }
+
+    fn part_2(input: &Self::Input, params: &Params) -> Self::Output2 {
+        let mut uncovered = solution_2(input, params.value::<i32>("max"));
+        match (uncovered.next(), uncovered.next()) {
+            (Some(position), None) => Diagnosed {
+                answer: Some(tuning_frequency(position)),
+                diagnostics: None,
+            },
+            (None, _) => Diagnosed {
+                answer: None,
+                diagnostics: Some("expected exactly one uncovered position, found none".to_owned()),
+            },
+            (Some(_), Some(_)) => Diagnosed {
+                answer: None,
+                diagnostics: Some(
+                    "expected exactly one uncovered position, found several".to_owned(),
+                ),
+            },
+        }
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Sensor>, ParseError> {
+    let pattern =
+        Regex::new(r"^Sensor at x=(-?\d+), y=(-?\d+): closest beacon is at x=(-?\d+), y=(-?\d+)$")
+            .expect("valid regex");
+    lines(input)
+        .map(|line| {
+            let captures = pattern.captures(line.text).ok_or_else(|| {
+                line.error(
+                    1,
+                    "expected `Sensor at x=<x>, y=<y>: closest beacon is at x=<x>, y=<y>`",
+                )
+            })?;
+            let capture = |i| line.parse_at(captures.get(i).unwrap().as_str());
+            let sensor_x = capture(1)?;
+            let sensor_y = capture(2)?;
+            let beacon_x = capture(3)?;
+            let beacon_y = capture(4)?;
+            Ok(Sensor {
+                position: Point {
+                    x: sensor_x,
+                    y: sensor_y,
+                },
+                closest_beacon: Point {
+                    x: beacon_x,
+                    y: beacon_y,
+                },
+            })
+        })
+        .collect()
+}
+
+/// The sensors' [`Coverage`]. Kept as a compatibility alias for [`Coverage::new`].
+pub fn all_coverage_by_row(sensors: Vec<Sensor>) -> Coverage {
+    Coverage::new(sensors)
+}
+
+/// Counts the positions on `row` where there cannot be a beacon.
+pub fn solution_1(coverage: &Coverage, row: i32) -> usize {
+    let covered: i64 = coverage
+        .row(row)
+        .iter()
+        .map(|(start, end)| end - start + 1)
+        .sum();
+    // Every beacon is some sensor's closest, so it lies inside the covered ranges.
+    let beacons = coverage
+        .beacons
+        .iter()
+        .filter(|beacon| beacon.y == row)
+        .count();
+    covered as usize - beacons
+}
+
+/// Every position with both coordinates in `0..=max` that no sensor covers, row by row.
+pub fn solution_2(coverage: &Coverage, max: i32) -> impl Iterator<Item = Point> + '_ {
+    (0..=max).flat_map(move |row| {
+        let max = i64::from(max);
+        // The gaps between the ranges, plus whatever is left after the last one.
+        let mut gaps = Vec::new();
+        let mut x = 0;
+        for (start, end) in coverage.row(row) {
+            if start > x {
+                gaps.push((x, (start - 1).min(max)));
+            }
+            x = x.max(end + 1);
+        }
+        gaps.push((x, max));
+        gaps.into_iter()
+            .filter(|(start, end)| start <= end)
+            .flat_map(move |(start, end)| (start..=end).map(move |x| Point::new(x as i32, row)))
+    })
+}
+
+/// The distress beacon's tuning frequency, `x * 4000000 + y`.
+pub fn tuning_frequency(position: Point) -> i128 {
+    position.x as i128 * 4000000 + position.y as i128
+}
+
+#[test]
+fn test_perimeter() {
+    let sensor = Sensor {
+        position: Point { x: 0, y: 0 },
+        closest_beacon: Point { x: 1, y: 1 },
+    };
+    assert_eq!(
+        sensor.perimiter(),
+        vec![
+            Point { x: -1, y: 1 },
+            Point { x: -2, y: 0 },
+            Point { x: -1, y: -1 },
+            Point { x: 0, y: -2 },
+            Point { x: 1, y: -1 },
+            Point { x: 2, y: 0 },
+            Point { x: 1, y: 1 },
+            Point { x: 0, y: 2 },
+        ]
+    );
+}
+
+#[test]
+fn test_perimeter_by_row() {
+    let sensor = Sensor {
+        position: Point { x: 0, y: 0 },
+        closest_beacon: Point { x: 1, y: 1 },
+    };
+    assert_eq!(
+        (-3..=3)
+            .map(|row| sensor.range_on_row(row))
+            .collect::<Vec<_>>(),
+        vec![
+            None,
+            Some((0, 0)),
+            Some((-1, 1)),
+            Some((-2, 2)),
+            Some((-1, 1)),
+            Some((0, 0)),
+            None,
+        ]
+    );
+}
+
+#[test]
+fn test_coverage_row() {
+    let sensor = |x, y, beacon_x, beacon_y| Sensor {
+        position: Point::new(x, y),
+        closest_beacon: Point::new(beacon_x, beacon_y),
+    };
+    let coverage = Coverage::new(vec![sensor(0, 0, 1, 1), sensor(4, 0, 4, 1)]);
+    assert_eq!(coverage.row(0), [(-2, 5)]);
+    assert_eq!(coverage.row(-1), [(-1, 1), (4, 4)]);
+    assert_eq!(coverage.row(2), [(0, 0)]);
+    assert_eq!(coverage.row(3), []);
+
+    let far = Coverage::new(vec![sensor(i32::MIN, i32::MIN, i32::MAX, i32::MAX)]);
+    assert_eq!(solution_1(&far, 0), 12884901885);
+}
+
+#[cfg(test)]
+use proptest::prelude::*;
+
+/// Whether any sensor is at least as close to `position` as to its own beacon.
+#[cfg(test)]
+fn reference_is_covered(sensors: &[Sensor], position: Point) -> bool {
+    sensors.iter().any(|sensor| {
+        sensor.position.manhattan_distance(&position) <= sensor.manhatan_distance_to_beacon()
+    })
+}
+
+#[cfg(test)]
+fn sensor_strategy() -> impl Strategy<Value = (Point, Point)> {
+    (0..=20, 0..=20, -6..=6, -6..=6)
+        .prop_map(|(x, y, dx, dy)| (Point::new(x, y), Point::new(x + dx, y + dy)))
+}
+
+#[cfg(test)]
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(1000))]
+    #[test]
+    fn test_solutions_match_reference(
+        sensors in prop::collection::vec(sensor_strategy(), 1..6),
+        row in -2..=22,
+    ) {
+        let text: String = sensors
+            .iter()
+            .map(|(sensor, beacon)| {
+                format!(
+                    "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+                    sensor.x, sensor.y, beacon.x, beacon.y
+                )
+            })
+            .collect();
+        let sensors = parse_input(&text).unwrap();
+        let coverage = Coverage::new(sensors.clone());
+        let beacons: HashSet<Point> = sensors.iter().map(|sensor| sensor.closest_beacon).collect();
+        let expected_1 = (-20..=40)
+            .map(|x| Point::new(x, row))
+            .filter(|&p| reference_is_covered(&sensors, p) && !beacons.contains(&p))
+            .count();
+        prop_assert_eq!(solution_1(&coverage, row), expected_1);
+        let expected_2: Vec<Point> = (0..=20)
+            .flat_map(|y| (0..=20).map(move |x| Point::new(x, y)))
+            .filter(|&p| !reference_is_covered(&sensors, p))
+            .collect();
+        prop_assert_eq!(solution_2(&coverage, 20).collect::<Vec<_>>(), expected_2);
+    }
+}
@@ -0,0 +1,236 @@
+use GameResult::*;
+
+/// How a round ends for the player.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum GameResult {
+    Win,
+    Loss,
+    Tie,
+}
+
+impl GameResult {
+    pub fn named(name: &str) -> Option<Self> {
+        match name {
+            "win" => Some(Win),
+            "lose" => Some(Loss),
+            "draw" => Some(Tie),
+            _ => None,
+        }
+    }
+}
+
+/// One of a game's moves, by its position in [`Game::moves`].
+#[derive(PartialEq, Eq, Debug, Clone, Copy, PartialOrd, Ord)]
+pub struct Move(usize);
+
+/// A game like Rock Paper Scissors: its moves and what each scores, which move beats which,
+/// and what winning, drawing and losing score.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    moves: Vec<(String, i32)>,
+    /// `beats[a][b]` when move `a` beats move `b`.
+    beats: Vec<Vec<bool>>,
+    /// The scores for a win, a tie and a loss.
+    results: [i32; 3],
+}
+
+impl Game {
+    pub const NAMES: [&'static str; 2] = ["rps", "rpsls"];
+
+    /// A game where each move beats the half of the others just before it in `moves`,
+    /// wrapping around, so that with an odd number of moves every pair has a winner.
+    pub fn cyclic(moves: &[(&str, i32)], results: [i32; 3]) -> Self {
+        let n = moves.len();
+        let beats = (0..n)
+            .map(|a| {
+                (0..n)
+                    .map(|b| (1..=(n - 1) / 2).contains(&((a + n - b) % n)))
+                    .collect()
+            })
+            .collect();
+        Self {
+            moves: Self::owned(moves),
+            beats,
+            results,
+        }
+    }
+
+    /// A game where `rules` lists each `(winner, loser)` pair; moves not paired tie.
+    pub fn new(
+        moves: &[(&str, i32)],
+        rules: &[(&str, &str)],
+        results: [i32; 3],
+    ) -> Result<Self, String> {
+        let mut game = Self {
+            moves: Self::owned(moves),
+            beats: vec![vec![false; moves.len()]; moves.len()],
+            results,
+        };
+        for (i, (name, _)) in moves.iter().enumerate() {
+            if game.find(name) != Some(Move(i)) {
+                return Err(format!("`{}` is given twice", name));
+            }
+        }
+        for &(winner, loser) in rules {
+            let find = |name| game.find(name).ok_or_else(|| format!("no move `{}`", name));
+            let (Move(a), Move(b)) = (find(winner)?, find(loser)?);
+            if a == b || game.beats[b][a] {
+                return Err(format!("`{}` cannot beat `{}`", winner, loser));
+            }
+            game.beats[a][b] = true;
+        }
+        Ok(game)
+    }
+
+    /// Rock Paper Scissors as the elves score it.
+    pub fn rps() -> Self {
+        Self::cyclic(&[("rock", 1), ("paper", 2), ("scissors", 3)], [6, 3, 0])
+    }
+
+    /// Rock Paper Scissors Lizard Spock, scored like [`Game::rps`] with lizard and Spock
+    /// worth 4 and 5.
+    pub fn rpsls() -> Self {
+        let moves = [
+            ("rock", 1),
+            ("Spock", 5),
+            ("paper", 2),
+            ("lizard", 4),
+            ("scissors", 3),
+        ];
+        Self::cyclic(&moves, [6, 3, 0])
+    }
+
+    pub fn named(name: &str) -> Option<Self> {
+        match name {
+            "rps" => Some(Self::rps()),
+            "rpsls" => Some(Self::rpsls()),
+            _ => None,
+        }
+    }
+
+    fn owned(moves: &[(&str, i32)]) -> Vec<(String, i32)> {
+        moves
+            .iter()
+            .map(|&(name, score)| (name.to_owned(), score))
+            .collect()
+    }
+
+    pub fn moves(&self) -> impl Iterator<Item = Move> {
+        (0..self.moves.len()).map(Move)
+    }
+
+    pub fn find(&self, name: &str) -> Option<Move> {
+        self.moves.iter().position(|(n, _)| n == name).map(Move)
+    }
+
+    pub fn name(&self, Move(i): Move) -> &str {
+        &self.moves[i].0
+    }
+
+    pub fn round_result(&self, Move(player): Move, Move(opponent): Move) -> GameResult {
+        if self.beats[player][opponent] {
+            Win
+        } else if self.beats[opponent][player] {
+            Loss
+        } else {
+            Tie
+        }
+    }
+
+    pub fn round_value(&self, player: Move, opponent: Move) -> i32 {
+        let result = match self.round_result(player, opponent) {
+            Win => self.results[0],
+            Tie => self.results[1],
+            Loss => self.results[2],
+        };
+        result + self.moves[player.0].1
+    }
+
+    /// The best scoring move that ends a round against `opponent` with `result`, if any does.
+    pub fn respond(&self, opponent: Move, result: GameResult) -> Option<Move> {
+        self.moves()
+            .filter(|&player| self.round_result(player, opponent) == result)
+            .max_by_key(|&player| {
+                (
+                    self.round_value(player, opponent),
+                    std::cmp::Reverse(player),
+                )
+            })
+    }
+}
+
+/// Lists `words` as "a, b or c".
+pub(crate) fn one_of<'a>(words: impl IntoIterator<Item = &'a str>) -> String {
+    let words: Vec<&str> = words.into_iter().collect();
+    match words.split_last() {
+        Some((last, [])) => last.to_string(),
+        Some((last, rest)) => format!("{} or {}", rest.join(", "), last),
+        None => "nothing".to_owned(),
+    }
+}
+
+#[test]
+fn test_rps() {
+    let game = Game::rps();
+    let [rock, paper, scissors] =
+        ["rock", "paper", "scissors"].map(|name| game.find(name).unwrap());
+    assert_eq!(game.round_result(paper, rock), Win);
+    assert_eq!(game.round_result(rock, scissors), Win);
+    assert_eq!(game.round_result(rock, paper), Loss);
+    assert_eq!(game.round_result(scissors, scissors), Tie);
+    assert_eq!(game.round_value(paper, rock), 8);
+    assert_eq!(game.round_value(rock, paper), 1);
+    assert_eq!(game.respond(rock, Loss), Some(scissors));
+}
+
+#[test]
+fn test_rpsls() {
+    let rules = [
+        ("scissors", "paper"),
+        ("paper", "rock"),
+        ("rock", "lizard"),
+        ("lizard", "Spock"),
+        ("Spock", "scissors"),
+        ("scissors", "lizard"),
+        ("lizard", "paper"),
+        ("paper", "Spock"),
+        ("Spock", "rock"),
+        ("rock", "scissors"),
+    ];
+    let moves = [
+        ("rock", 1),
+        ("Spock", 5),
+        ("paper", 2),
+        ("lizard", 4),
+        ("scissors", 3),
+    ];
+    let game = Game::rpsls();
+    assert_eq!(Game::new(&moves, &rules, [6, 3, 0]), Ok(game.clone()));
+    let [rock, spock, lizard, scissors] =
+        ["rock", "Spock", "lizard", "scissors"].map(|name| game.find(name).unwrap());
+    // Both Spock and paper beat rock, and Spock scores more.
+    assert_eq!(game.respond(rock, Win), Some(spock));
+    assert_eq!(game.respond(spock, Loss), Some(scissors));
+    assert_eq!(game.round_value(lizard, spock), 10);
+}
+
+#[test]
+fn test_game_rules() {
+    let moves = [("high", 2), ("low", 1)];
+    let game = Game::new(&moves, &[("high", "low")], [1, 0, -1]).unwrap();
+    let low = game.find("low").unwrap();
+    assert_eq!(game.respond(low, Tie), Some(low));
+    assert_eq!(game.respond(low, Loss), None);
+    assert_eq!(
+        Game::new(&moves, &[("high", "low"), ("low", "high")], [1, 0, -1]),
+        Err("`low` cannot beat `high`".to_owned())
+    );
+    assert_eq!(
+        Game::new(&moves, &[("high", "mid")], [1, 0, -1]),
+        Err("no move `mid`".to_owned())
+    );
+    assert_eq!(
+        Game::new(&[("a", 1), ("a", 2)], &[], [1, 0, -1]),
+        Err("`a` is given twice".to_owned())
+    );
+}
@@ -0,0 +1,20 @@
+use aoc_common::{Generate, Rng};
+
+use crate::Day2;
+
+/// `size` is the number of rounds in the strategy guide.
+impl Generate for Day2 {
+    const DEFAULT_SIZE: usize = 2500;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|_| {
+                format!(
+                    "{} {}\n",
+                    rng.choose(&['A', 'B', 'C']),
+                    rng.choose(&['X', 'Y', 'Z'])
+                )
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,391 @@
+//! Day 2: Rock Paper Scissors, scored from a strategy guide of `A`-`C` against `X`-`Z`.
+
+pub mod game;
+pub mod gen;
+
+use std::collections::BTreeMap;
+
+use aoc_common::{lines, parse::Line, Params, ParseError, Solution};
+
+use game::{one_of, Game, GameResult, Move};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    type Input = Vec<Round>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input, _: &Params) -> Self::Output1 {
+        solution_1(input)
+    }
+
+    fn part_2(input: &Self::Input, _: &Params) -> Self::Output2 {
+        solution_2(input)
+    }
+}
+
+/// A symbol from the strategy guide, with the column it starts at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Symbol {
+    pub text: String,
+    pub column: usize,
+}
+
+/// One round of the guide as written, before an [`Encoding`] gives its symbols a meaning.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Round {
+    pub line: usize,
+    pub opponent: Symbol,
+    pub response: Symbol,
+}
+
+/// Reads each round as the opponent's letter and the response letter, which must be ones
+/// both standard encodings know.
+pub fn parse_input(input: &str) -> Result<Vec<Round>, ParseError> {
+    let rounds = parse_rounds(input)?;
+    for encoding in [Encoding::moves(), Encoding::results()] {
+        for round in &rounds {
+            encoding.decode(round)?;
+        }
+    }
+    Ok(rounds)
+}
+
+/// Reads each round as two symbols separated by a space, whatever they are.
+pub fn parse_rounds(input: &str) -> Result<Vec<Round>, ParseError> {
+    lines(input)
+        .map(|line| {
+            let (opponent, response) = line.split_once(" ")?;
+            let symbol = |text: &str| Symbol {
+                text: text.to_owned(),
+                column: line.column_of(text),
+            };
+            Ok(Round {
+                line: line.number,
+                opponent: symbol(opponent),
+                response: symbol(response),
+            })
+        })
+        .collect()
+}
+
+/// The total score when `X`, `Y` and `Z` are the shapes to play.
+pub fn solution_1(input: &[Round]) -> i32 {
+    score(input, &Encoding::moves()).expect("checked when parsing")
+}
+
+/// The total score when `X`, `Y` and `Z` are the results to aim for.
+pub fn solution_2(input: &[Round]) -> i32 {
+    score(input, &Encoding::results()).expect("checked when parsing")
+}
+
+/// The total score of the rounds when their symbols mean what `encoding` says.
+pub fn score(rounds: &[Round], encoding: &Encoding) -> Result<i32, ParseError> {
+    rounds
+        .iter()
+        .map(|round| {
+            let (opp, player) = encoding.decode(round)?;
+            Ok(encoding.game.round_value(player, opp))
+        })
+        .sum()
+}
+
+/// What a symbol in the response column tells the player to do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Response {
+    Play(Move),
+    Aim(GameResult),
+}
+
+/// What the symbols of a strategy guide stand for in a [`Game`]: the opponent's are always
+/// moves, and the player's are moves to play or results to aim for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Encoding {
+    game: Game,
+    opponent: BTreeMap<String, Move>,
+    response: BTreeMap<String, Response>,
+}
+
+impl Encoding {
+    pub const NAMES: [&'static str; 2] = ["moves", "results"];
+
+    /// `X`, `Y` and `Z` are rock, paper and scissors, as part 1 first reads the guide.
+    pub fn moves() -> Self {
+        Self::parse("opponent A=rock B=paper C=scissors\nresponse X=rock Y=paper Z=scissors")
+            .expect("a valid encoding")
+    }
+
+    /// `X`, `Y` and `Z` mean lose, draw and win, as part 2 reads it.
+    pub fn results() -> Self {
+        Self::parse("opponent A=rock B=paper C=scissors\nresponse X=lose Y=draw Z=win")
+            .expect("a valid encoding")
+    }
+
+    pub fn named(name: &str) -> Option<Self> {
+        match name {
+            "moves" => Some(Self::moves()),
+            "results" => Some(Self::results()),
+            _ => None,
+        }
+    }
+
+    /// Reads an encoding for [`Game::rps`], or for another game named by a first line like
+    /// `game rpsls`. See [`Encoding::parse_in`].
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        Self::parse_in(Game::rps(), text)
+    }
+
+    /// Reads an encoding for `game` from lines like `opponent A=rock B=paper C=scissors` and
+    /// `response X=lose Y=draw Z=win`, one for each column. Entries are separated by spaces
+    /// or commas, and `#` starts a comment.
+    pub fn parse_in(mut game: Game, text: &str) -> Result<Self, ParseError> {
+        let mut named = false;
+        let mut opponent = None;
+        let mut response = None;
+        for line in lines(text) {
+            let content = line.text.split('#').next().unwrap_or_default();
+            let mut words = content
+                .split(|c: char| c.is_whitespace() || c == ',')
+                .filter(|word| !word.is_empty());
+            let Some(column) = words.next() else {
+                continue;
+            };
+            if column == "game" {
+                if opponent.is_some() || response.is_some() {
+                    let message = "`game` must come before `opponent` and `response`";
+                    return Err(line.error_at(column, message));
+                }
+                if named {
+                    return Err(line.error_at(column, "`game` is given twice"));
+                }
+                let expected = format!("expected {}", one_of(Game::NAMES));
+                let Some(name) = words.next() else {
+                    return Err(line.error(line.text.chars().count() + 1, expected));
+                };
+                let named_game = Game::named(name)
+                    .ok_or_else(|| line.error_at(name, format!("{}, not `{}`", expected, name)))?;
+                game = named_game;
+                named = true;
+                continue;
+            }
+            let entries: Vec<(&str, &str)> = words
+                .map(|word| {
+                    word.split_once('=')
+                        .filter(|(symbol, meaning)| !symbol.is_empty() && !meaning.is_empty())
+                        .ok_or_else(|| {
+                            line.error_at(
+                                word,
+                                format!("expected `SYMBOL=MEANING`, not `{}`", word),
+                            )
+                        })
+                })
+                .collect::<Result<_, _>>()?;
+            let moves = one_of(game.moves().map(|m| game.name(m)));
+            match column {
+                "opponent" if opponent.is_none() => {
+                    opponent = Some(table(&line, &entries, |meaning| {
+                        game.find(meaning)
+                            .ok_or_else(|| format!("expected {}", moves))
+                    })?);
+                }
+                "response" if response.is_none() => {
+                    response = Some(table(&line, &entries, |meaning| {
+                        match (game.find(meaning), GameResult::named(meaning)) {
+                            (Some(m), _) => Ok(Response::Play(m)),
+                            (_, Some(result)) => Ok(Response::Aim(result)),
+                            _ => Err(format!("expected a move ({}) or lose, draw or win", moves)),
+                        }
+                    })?);
+                }
+                "opponent" | "response" => {
+                    return Err(line.error_at(column, format!("`{}` is given twice", column)))
+                }
+                _ => {
+                    return Err(line.error_at(
+                        column,
+                        format!("expected `opponent` or `response`, not `{}`", column),
+                    ))
+                }
+            }
+        }
+        let missing = |column| ParseError::new(1, 1, format!("no `{}` line", column));
+        Ok(Self {
+            game,
+            opponent: opponent.ok_or_else(|| missing("opponent"))?,
+            response: response.ok_or_else(|| missing("response"))?,
+        })
+    }
+
+    /// The moves played in `round`, as `(opponent, player)`.
+    fn decode(&self, round: &Round) -> Result<(Move, Move), ParseError> {
+        let opp = lookup(&self.opponent, round.line, &round.opponent)?;
+        let player = match lookup(&self.response, round.line, &round.response)? {
+            Response::Play(player) => player,
+            Response::Aim(result) => self.game.respond(opp, result).ok_or_else(|| {
+                let verb = match result {
+                    GameResult::Win => "beats",
+                    GameResult::Tie => "ties with",
+                    GameResult::Loss => "loses to",
+                };
+                ParseError::new(
+                    round.line,
+                    round.response.column,
+                    format!("no move {} {}", verb, self.game.name(opp)),
+                )
+            })?,
+        };
+        Ok((opp, player))
+    }
+}
+
+/// Reads one column's `SYMBOL=MEANING` entries, each meaning through `meaning`.
+fn table<T>(
+    line: &Line,
+    entries: &[(&str, &str)],
+    meaning: impl Fn(&str) -> Result<T, String>,
+) -> Result<BTreeMap<String, T>, ParseError> {
+    let mut table = BTreeMap::new();
+    for &(symbol, text) in entries {
+        let value = meaning(text)
+            .map_err(|expected| line.error_at(text, format!("{}, not `{}`", expected, text)))?;
+        if table.insert(symbol.to_owned(), value).is_some() {
+            return Err(line.error_at(symbol, format!("`{}` is given twice", symbol)));
+        }
+    }
+    if table.is_empty() {
+        return Err(line.error(line.text.chars().count() + 1, "expected some symbols"));
+    }
+    Ok(table)
+}
+
+fn lookup<T: Copy>(
+    table: &BTreeMap<String, T>,
+    line: usize,
+    symbol: &Symbol,
+) -> Result<T, ParseError> {
+    table.get(&symbol.text).copied().ok_or_else(|| {
+        let expected = one_of(table.keys().map(String::as_str));
+        ParseError::new(
+            line,
+            symbol.column,
+            format!("expected {}, found `{}`", expected, symbol.text),
+        )
+    })
+}
+
+#[test]
+fn test_standard_encodings() {
+    let rounds = parse_input(include_str!("../example.txt")).unwrap();
+    assert_eq!(solution_1(&rounds), 15);
+    assert_eq!(solution_2(&rounds), 12);
+    assert_eq!(
+        parse_input("A Y\nB W\n").unwrap_err(),
+        ParseError::new(2, 3, "expected X, Y or Z, found `W`")
+    );
+    assert_eq!(
+        parse_input("D Y\n").unwrap_err(),
+        ParseError::new(1, 1, "expected A, B or C, found `D`")
+    );
+}
+
+#[test]
+fn test_custom_encoding() {
+    let encoding = Encoding::parse(
+        "# the elves' shorthand\nopponent R=rock, P=paper, S=scissors\n\
+         response R=rock P=paper S=scissors = =draw\n",
+    );
+    assert_eq!(
+        encoding.unwrap_err(),
+        ParseError::new(3, 36, "expected `SYMBOL=MEANING`, not `=`")
+    );
+    let encoding = Encoding::parse(
+        "opponent R=rock, P=paper, S=scissors\nresponse R=rock P=paper S=scissors ?=draw\n",
+    )
+    .unwrap();
+    let rounds = parse_rounds("R P\nS ?\nP Q\n").unwrap();
+    assert_eq!(score(&rounds[..2], &encoding).unwrap(), 8 + 6);
+    assert_eq!(
+        score(&rounds, &encoding).unwrap_err(),
+        ParseError::new(3, 3, "expected ?, P, R or S, found `Q`")
+    );
+}
+
+#[test]
+fn test_encoding_errors() {
+    let error = |text: &str| Encoding::parse(text).unwrap_err().to_string();
+    assert_eq!(
+        error("opponent A=rock\n"),
+        "line 1, column 1: no `response` line"
+    );
+    assert_eq!(
+        error("opponent A=lose\nresponse X=win"),
+        "line 1, column 12: expected rock, paper or scissors, not `lose`"
+    );
+    assert_eq!(
+        error("opponent A=rock A=paper\nresponse X=win"),
+        "line 1, column 17: `A` is given twice"
+    );
+    assert_eq!(
+        error("opponent A=rock\nresponse X=tie"),
+        "line 2, column 12: expected a move (rock, paper or scissors) or lose, draw or win, not `tie`"
+    );
+    assert_eq!(
+        error("player A=rock"),
+        "line 1, column 1: expected `opponent` or `response`, not `player`"
+    );
+    assert_eq!(
+        error("opponent A=rock\nresponse\n"),
+        "line 2, column 9: expected some symbols"
+    );
+    assert_eq!(
+        error("game chess\n"),
+        "line 1, column 6: expected rps or rpsls, not `chess`"
+    );
+    assert_eq!(
+        error("opponent A=rock\ngame rpsls\nresponse X=win"),
+        "line 2, column 1: `game` must come before `opponent` and `response`"
+    );
+}
+
+#[test]
+fn test_encoding_in_game() {
+    let game = Game::new(&[("high", 2), ("low", 1)], &[("high", "low")], [1, 0, -1]).unwrap();
+    let encoding = Encoding::parse_in(game, "opponent H=high L=low\nresponse W=win").unwrap();
+    let rounds = parse_rounds("L W\nH W\n").unwrap();
+    assert_eq!(score(&rounds[..1], &encoding).unwrap(), 3);
+    assert_eq!(
+        score(&rounds, &encoding).unwrap_err(),
+        ParseError::new(2, 3, "no move beats high")
+    );
+}
+
+#[test]
+fn test_rpsls_encoding() {
+    let encoding = Encoding::parse(
+        "game rpsls\n\
+         opponent A=rock B=paper C=scissors D=lizard E=Spock\n\
+         response V=lose W=draw X=win Y=Spock Z=lizard\n",
+    )
+    .unwrap();
+    let rounds = parse_rounds("A X\nE V\nB Z\nC Y\n").unwrap();
+    // Spock beats rock, scissors is the best scoring way to lose to Spock, lizard eats
+    // paper and Spock smashes scissors.
+    assert_eq!(
+        score(&rounds, &encoding).unwrap(),
+        (6 + 5) + 3 + (6 + 4) + (6 + 5)
+    );
+    assert_eq!(
+        Encoding::parse("opponent A=rock\nresponse X=Spock").unwrap_err(),
+        ParseError::new(
+            2,
+            12,
+            "expected a move (rock, paper or scissors) or lose, draw or win, not `Spock`"
+        )
+    );
+}
@@ -1,137 +1,80 @@
-use std::io::stdin;
-use GameResult::*;
-use RPS::*;
+use std::{env::args, fs, process::exit};
 
-fn main() -> Result<(), String> {
-    let input = parse_input()?;
-    println!("{:?}", solution_1(&input));
-    println!("{:?}", solution_2(&input));
-    Ok(())
-}
-
-fn parse_input() -> Result<Vec<(String, String)>, String> {
-    stdin()
-        .lines()
-        .map(|line| parse_line(line.map_err(|e| e.to_string())?.as_str()))
-        .collect()
-}
-
-fn parse_line(line: &str) -> Result<(String, String), String> {
-    let mut split = line.split(" ");
-    let first = split.next().ok_or("invalid line")?.to_string();
-    let snd = split.next().ok_or("invalid line")?.to_string();
-    Ok((first, snd))
-}
-
-fn solution_1(input: &Vec<(String, String)>) -> i32 {
-    input
-        .iter()
-        .map(solution_1_interpret_pair)
-        .map(|(opp, player)| round_value(&(player, opp)))
-        .sum()
-}
+use aoc_common::{
+    solution::{parse_args, run},
+    InputSource, Solution,
+};
+use day2::{parse_rounds, score, Day2, Encoding};
 
-fn solution_1_interpret_pair(pair: &(String, String)) -> (RPS, RPS) {
-    let (opp_str, player_str) = pair;
-    let opp = match opp_str.as_str() {
-        "A" => Rock,
-        "B" => Paper,
-        "C" => Scissors,
-        _ => panic!(),
-    };
-    let player = match player_str.as_str() {
-        "X" => Rock,
-        "Y" => Paper,
-        "Z" => Scissors,
-        _ => panic!(),
+fn main() {
+    let mut args: Vec<String> = args().skip(1).collect();
+    let parsed =
+        take_encoding(&mut args).and_then(|encoding| Ok((encoding, parse_args::<Day2>(args)?)));
+    let (encoding, (source, _)) = match parsed {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            eprintln!(
+                "usage: day{} [--input <path> | --example | -] [--param <name=value>]... \
+                 [--encoding <{}|spec|path>]",
+                Day2::DAY,
+                Encoding::NAMES.join("|")
+            );
+            exit(2);
+        }
     };
-    (opp, player)
+    match encoding {
+        Some((name, encoding)) => println!("score ({}): {}", name, scored(&source, &encoding)),
+        None => run::<Day2>(),
+    }
 }
 
-fn solution_2(input: &Vec<(String, String)>) -> i32 {
-    input
+/// Takes `--encoding` out of `args`: a standard encoding's name, a spec given inline with
+/// `;` between its lines, or the path of a file holding one. The encoding comes with a name
+/// to label its score with.
+fn take_encoding(args: &mut Vec<String>) -> Result<Option<(String, Encoding)>, String> {
+    let Some(i) = args
         .iter()
-        .map(solution_2_interpret_pair)
-        .map(|(opp, player)| round_value(&(player, opp)))
-        .sum()
-}
-
-fn solution_2_interpret_pair(pair: &(String, String)) -> (RPS, RPS) {
-    let (opp_str, player_str) = pair;
-    let opp = match opp_str.as_str() {
-        "A" => Rock,
-        "B" => Paper,
-        "C" => Scissors,
-        _ => panic!(),
-    };
-    let desired_result = match player_str.as_str() {
-        "X" => Loss,
-        "Y" => Tie,
-        "Z" => Win,
-        _ => panic!(),
+        .position(|arg| arg == "--encoding" || arg.starts_with("--encoding="))
+    else {
+        return Ok(None);
     };
-    let player = match desired_result {
-        Tie => opp,
-        Loss => match opp {
-            Rock => Scissors,
-            Paper => Rock,
-            Scissors => Paper,
-        },
-        Win => match opp {
-            Rock => Paper,
-            Paper => Scissors,
-            Scissors => Rock,
-        },
+    let arg = args.remove(i);
+    let value = match arg.strip_prefix("--encoding=") {
+        Some(value) => value.to_owned(),
+        None if i < args.len() => args.remove(i),
+        None => return Err("--encoding needs a name, spec or path".to_owned()),
     };
-    (opp, player)
-}
-
-#[derive(PartialEq, Eq, Debug, Clone, Copy, PartialOrd, Ord)]
-enum RPS {
-    Rock,
-    Paper,
-    Scissors,
-}
-
-#[derive(PartialEq, Eq, Debug, Clone, Copy)]
-enum GameResult {
-    Win,
-    Loss,
-    Tie,
-}
-
-impl GameResult {
-    fn value(&self) -> i32 {
-        match self {
-            Win => 6,
-            Tie => 3,
-            Loss => 0,
-        }
+    if let Some(encoding) = Encoding::named(&value) {
+        return Ok(Some((value, encoding)));
     }
-}
-
-impl RPS {
-    fn value(&self) -> i32 {
-        match self {
-            Rock => 1,
-            Paper => 2,
-            Scissors => 3,
-        }
-    }
-}
-
-fn round_result(round: &(RPS, RPS)) -> GameResult {
-    match round {
-        (x, y) if x == y => Tie,
-        (Paper, Rock) => Win,
-        (Rock, Scissors) => Win,
-        (Scissors, Paper) => Win,
-        _ => Loss,
+    let (name, text) = if value.contains(['=', ';']) {
+        ("inline encoding".to_owned(), value.replace(';', "\n"))
+    } else {
+        let text = fs::read_to_string(&value)
+            .map_err(|e| format!("could not read encoding {}: {}", value, e))?;
+        (value, text)
+    };
+    match Encoding::parse(&text) {
+        Ok(encoding) => Ok(Some((name, encoding))),
+        Err(e) => Err(format!("{}: {}", name, e.diagnostic(&text))),
     }
 }
 
-fn round_value(round: &(RPS, RPS)) -> i32 {
-    let result = round_result(round);
-    let (player, _) = round;
-    return result.value() + player.value();
+/// Scores the input with `encoding`, which may know symbols the standard ones don't.
+fn scored(source: &InputSource, encoding: &Encoding) -> i32 {
+    let input = source.read(Day2::DAY).unwrap_or_else(|e| {
+        eprintln!("error: {}", e);
+        exit(1);
+    });
+    parse_rounds(&input)
+        .and_then(|rounds| score(&rounds, encoding))
+        .unwrap_or_else(|e| {
+            eprintln!(
+                "error: {}: {}",
+                source.name(Day2::DAY),
+                e.diagnostic(&input)
+            );
+            exit(1);
+        })
 }
@@ -0,0 +1,40 @@
+use aoc_common::{Generate, Rng};
+
+use crate::Day3;
+
+const ITEMS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// `size` is the number of elf groups, each carrying three rucksacks.
+///
+/// Both compartments of a rucksack share exactly one item type, and the three rucksacks of a
+/// group share exactly one badge, as the puzzle promises.
+impl Generate for Day3 {
+    const DEFAULT_SIZE: usize = 100;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut out = String::new();
+        for _ in 0..size {
+            let mut items = ITEMS.to_vec();
+            rng.shuffle(&mut items);
+            let badge = items[0];
+            // Give each rucksack its own 17 item types so only the badge is common to all three.
+            for own in items[1..].chunks(17) {
+                let shared = own[0];
+                let (first_half, second_half) = own[1..].split_at(8);
+                let len = rng.range(3..=16) as usize;
+                let mut first = vec![shared, badge];
+                let mut second = vec![shared];
+                first.extend((2..len).map(|_| *rng.choose(first_half)));
+                second.extend((1..len).map(|_| *rng.choose(second_half)));
+                rng.shuffle(&mut first);
+                rng.shuffle(&mut second);
+                if rng.chance(1, 2) {
+                    std::mem::swap(&mut first, &mut second);
+                }
+                out.extend(first.iter().chain(second.iter()).map(|&b| b as char));
+                out.push('\n');
+            }
+        }
+        out
+    }
+}
@@ -0,0 +1,141 @@
+//! Day 3: Rucksack Reorganization, finding the items that rucksacks and groups share.
+
+pub mod gen;
+
+use std::collections::HashSet;
+
+use aoc_common::{lines, Params, ParseError, Solution};
+
+/// The items in each rucksack, in order.
+pub type Input = Vec<Vec<char>>;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    type Input = Input;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input, _: &Params) -> Self::Output1 {
+        solution_1(input)
+    }
+
+    fn part_2(input: &Self::Input, _: &Params) -> Self::Output2 {
+        solution_2(input)
+    }
+}
+
+/// Reads one rucksack per line. Items must be letters, each rucksack must have an item in
+/// both compartments, and each group of three a badge.
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
+    let mut rucksacks: Input = Vec::new();
+    for line in lines(input) {
+        let items = line
+            .text
+            .chars()
+            .enumerate()
+            .map(|(i, c)| {
+                if c.is_ascii_alphabetic() {
+                    Ok(c)
+                } else {
+                    Err(line.error(i + 1, format!("invalid item `{}`", c)))
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if !items.len().is_multiple_of(2) {
+            return Err(line.error(
+                items.len() + 1,
+                "the two compartments must hold as many items as each other",
+            ));
+        }
+        if shared_item(&items).is_none() {
+            return Err(line.error(1, "no item is in both compartments"));
+        }
+        rucksacks.push(items);
+        if rucksacks.len().is_multiple_of(3) && badge(&rucksacks[rucksacks.len() - 3..]).is_none() {
+            return Err(line.error(1, "no item is common to this group of three rucksacks"));
+        }
+    }
+    Ok(rucksacks)
+}
+
+fn shared_item(bag: &[char]) -> Option<char> {
+    let (fst, snd) = bag.split_at(bag.len() / 2);
+    fst.iter().find(|item| snd.contains(item)).copied()
+}
+
+fn badge(group: &[Vec<char>]) -> Option<char> {
+    let b_set = HashSet::<&char>::from_iter(group[1].iter());
+    let c_set = HashSet::<&char>::from_iter(group[2].iter());
+    group[0]
+        .iter()
+        .find(|x| b_set.contains(x) && c_set.contains(x))
+        .copied()
+}
+
+/// Sums the priorities of the item found in both halves of each rucksack.
+pub fn solution_1(input: &Input) -> i32 {
+    input
+        .iter()
+        .map(|bag| shared_item(bag).expect("checked when parsing"))
+        .map(|item| priority(&item))
+        .sum()
+}
+
+/// Sums the priorities of the badge shared by each group of three rucksacks.
+pub fn solution_2(input: &Input) -> i32 {
+    input
+        .chunks_exact(3)
+        .map(|group| badge(group).expect("checked when parsing"))
+        .map(|item| priority(&item))
+        .sum()
+}
+
+fn priority(c: &char) -> i32 {
+    match c {
+        'a'..='z' => *c as i32 - 'a' as i32 + 1,
+        'A'..='Z' => *c as i32 - 'A' as i32 + 27,
+        _ => panic!(),
+    }
+}
+
+#[test]
+fn test_priority() {
+    assert_eq!(priority(&'a'), 1);
+    assert_eq!(priority(&'b'), 2);
+    assert_eq!(priority(&'z'), 26);
+    assert_eq!(priority(&'A'), 27);
+    assert_eq!(priority(&'B'), 28);
+    assert_eq!(priority(&'Z'), 52);
+}
+
+#[test]
+fn test_parse_errors() {
+    let error = |input| parse_input(input).unwrap_err();
+    assert_eq!(
+        error("abc\n"),
+        ParseError::new(
+            1,
+            4,
+            "the two compartments must hold as many items as each other"
+        )
+    );
+    assert_eq!(
+        error("aA\n"),
+        ParseError::new(1, 1, "no item is in both compartments")
+    );
+    assert_eq!(
+        error("\n"),
+        ParseError::new(1, 1, "no item is in both compartments")
+    );
+    assert_eq!(
+        error("aa\nbb\naa\n"),
+        ParseError::new(3, 1, "no item is common to this group of three rucksacks")
+    );
+    assert_eq!(solution_1(&parse_input("abab\n").unwrap()), 1);
+}
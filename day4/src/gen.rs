@@ -0,0 +1,22 @@
+use aoc_common::{Generate, Rng};
+
+use crate::Day4;
+
+/// `size` is the number of elf pairs.
+impl Generate for Day4 {
+    const DEFAULT_SIZE: usize = 1000;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let range = |rng: &mut Rng| {
+            let start = rng.range(1..=99);
+            (start, rng.range(start..=99))
+        };
+        (0..size)
+            .map(|_| {
+                let (a, b) = range(rng);
+                let (c, d) = range(rng);
+                format!("{}-{},{}-{}\n", a, b, c, d)
+            })
+            .collect()
+    }
+}
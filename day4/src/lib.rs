@@ -0,0 +1,141 @@
+//! Day 4: Camp Cleanup, comparing the section ranges assigned to pairs of elves.
+
+pub mod gen;
+
+use aoc_common::{lines, parse::Line, Params, ParseError, Solution};
+
+/// An inclusive range of section IDs.
+pub type Pair = (i32, i32);
+pub type Input = Vec<(Pair, Pair)>;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    type Input = Input;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input, _: &Params) -> Self::Output1 {
+        solution_1(input)
+    }
+
+    fn part_2(input: &Self::Input, _: &Params) -> Self::Output2 {
+        solution_2(input)
+    }
+}
+
+/// Reads lines like `2-4,6-8` into pairs of ranges.
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
+    lines(input)
+        .map(|line| {
+            let (fst, snd) = line.split_once(",")?;
+            Ok((parse_range(&line, fst)?, parse_range(&line, snd)?))
+        })
+        .collect()
+}
+
+fn parse_range(line: &Line, range: &str) -> Result<Pair, ParseError> {
+    let (fst, snd) = range
+        .split_once('-')
+        .ok_or_else(|| line.error_at(range, format!("expected a range, found `{}`", range)))?;
+    Ok((line.parse_at(fst)?, line.parse_at(snd)?))
+}
+
+/// Counts the pairs where one range contains the other.
+pub fn solution_1(input: &Input) -> i32 {
+    input
+        .iter()
+        .filter(|(a, b)| one_contains_other(a, b))
+        .fold(0, |acc, _| acc + 1)
+}
+
+pub fn one_contains_other(a: &Pair, b: &Pair) -> bool {
+    let contains = |x: &Pair, y: &Pair| x.0 <= y.0 && x.1 >= y.1;
+    contains(a, b) || contains(b, a)
+}
+
+/// Counts the pairs whose ranges overlap at all.
+pub fn solution_2(input: &Input) -> i32 {
+    input
+        .iter()
+        .filter(|(a, b)| have_any_overlap(a, b))
+        .fold(0, |acc, _| acc + 1)
+}
+
+pub fn have_any_overlap(a: &Pair, b: &Pair) -> bool {
+    let overlap = |x: &Pair, y: &Pair| x.0 <= y.0 && x.1 >= y.0;
+    one_contains_other(a, b) || overlap(a, b) || overlap(b, a)
+}
+
+#[test]
+fn test_have_any_overlap() {
+    assert!(have_any_overlap(&(1, 3), &(2, 4)));
+    assert!(have_any_overlap(&(1, 1), &(1, 1)));
+    assert!(have_any_overlap(&(1, 4), &(2, 3)));
+    assert!(have_any_overlap(&(2, 3), &(1, 4)));
+    assert!(have_any_overlap(&(1, 4), &(2, 3)));
+    assert!(have_any_overlap(&(1, 5), &(5, 20)));
+
+    assert!(!have_any_overlap(&(1, 1), &(2, 2)));
+    assert!(!have_any_overlap(&(1, 5), &(9, 20)));
+    assert!(!have_any_overlap(&(2, 2), &(1, 1)));
+}
+
+#[test]
+fn test_parse_input_errors() {
+    assert_eq!(parse_input("2-4,6-8\n2-3,4-5\n").unwrap().len(), 2);
+    assert_eq!(
+        parse_input("2-4,6-8\n2-3 4-5\n").unwrap_err(),
+        ParseError::new(2, 8, "expected `,`")
+    );
+    assert_eq!(
+        parse_input("2-4,6-x\n").unwrap_err(),
+        ParseError::new(1, 7, "invalid number `x`")
+    );
+}
+
+#[cfg(test)]
+use proptest::prelude::*;
+
+/// Counts both parts by comparing the actual sets of sections each elf cleans.
+#[cfg(test)]
+fn reference_counts(input: &Input) -> (i32, i32) {
+    use std::collections::HashSet;
+    let sections = |(start, end): Pair| (start..=end).collect::<HashSet<i32>>();
+    input
+        .iter()
+        .fold((0, 0), |(contained, overlapping), &(a, b)| {
+            let (a, b) = (sections(a), sections(b));
+            (
+                contained + (a.is_subset(&b) || b.is_subset(&a)) as i32,
+                overlapping + !a.is_disjoint(&b) as i32,
+            )
+        })
+}
+
+#[cfg(test)]
+fn range_strategy() -> impl Strategy<Value = Pair> {
+    (1..=20, 0..=6).prop_map(|(start, len)| (start, start + len))
+}
+
+#[cfg(test)]
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(2000))]
+    #[test]
+    fn test_solutions_match_reference(
+        pairs in prop::collection::vec((range_strategy(), range_strategy()), 0..20)
+    ) {
+        let text: String = pairs
+            .iter()
+            .map(|((a, b), (c, d))| format!("{}-{},{}-{}\n", a, b, c, d))
+            .collect();
+        let input = parse_input(&text).unwrap();
+        prop_assert_eq!(&input, &pairs);
+        prop_assert_eq!((solution_1(&input), solution_2(&input)), reference_counts(&input));
+    }
+}
@@ -0,0 +1,166 @@
+use std::collections::VecDeque;
+
+use aoc_common::{
+    explore::{Exploration, Explore},
+    vis::{Palette, Role},
+    Params,
+};
+
+use crate::{move_all_at_once, move_one_at_a_time, Day5, Input};
+
+/// Both cranes working through the moves side by side, one move per step.
+struct CraneExploration<'a> {
+    input: &'a Input,
+    one_at_a_time: Vec<VecDeque<char>>,
+    all_at_once: Vec<VecDeque<char>>,
+    moves_made: usize,
+}
+
+impl Explore for Day5 {
+    fn explore<'a>(input: &'a Self::Input, _: &Params) -> Box<dyn Exploration + 'a> {
+        Box::new(CraneExploration {
+            input,
+            one_at_a_time: input.stacks.clone(),
+            all_at_once: input.stacks.clone(),
+            moves_made: 0,
+        })
+    }
+}
+
+/// Draws stacks the way the puzzle input does, with the top crates in `palette`'s active colour.
+fn draw(stacks: &[VecDeque<char>], palette: &Palette) -> String {
+    let tallest = stacks.iter().map(VecDeque::len).max().unwrap_or(0);
+    let mut lines: Vec<String> = (0..tallest)
+        .map(|level| {
+            stacks
+                .iter()
+                .map(|stack| {
+                    // Stacks keep their top crate first, and the drawing is level with the bottom.
+                    match (level + stack.len()).checked_sub(tallest) {
+                        Some(0) => palette.paint(&format!("[{}]", stack[0]), Role::Active),
+                        Some(i) => palette.paint(&format!("[{}]", stack[i]), Role::Wall),
+                        None => "   ".to_owned(),
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+                .trim_end()
+                .to_owned()
+        })
+        .collect();
+    lines.push(
+        (1..=stacks.len())
+            .map(|i| format!("{:^3}", i))
+            .collect::<Vec<_>>()
+            .join(" "),
+    );
+    lines.join("\n")
+}
+
+fn tops(stacks: &[VecDeque<char>]) -> String {
+    stacks
+        .iter()
+        .map(|stack| stack.front().unwrap_or(&' '))
+        .collect()
+}
+
+impl Exploration for CraneExploration<'_> {
+    fn step(&mut self) -> Option<String> {
+        let next = self.input.moves.get(self.moves_made)?;
+        move_one_at_a_time(&mut self.one_at_a_time, next);
+        move_all_at_once(&mut self.all_at_once, next);
+        self.moves_made += 1;
+        Some(format!(
+            "{} ({} of {})",
+            next,
+            self.moves_made,
+            self.input.moves.len()
+        ))
+    }
+
+    fn show(&self, palette: &Palette) -> String {
+        format!(
+            "CrateMover 9000\n{}\n\nCrateMover 9001\n{}",
+            draw(&self.one_at_a_time, palette),
+            draw(&self.all_at_once, palette)
+        )
+    }
+
+    fn query(&self, words: &[&str]) -> Result<String, String> {
+        match words {
+            ["top"] => Ok(format!(
+                "CrateMover 9000: {}\nCrateMover 9001: {}",
+                tops(&self.one_at_a_time),
+                tops(&self.all_at_once)
+            )),
+            ["stack", number] => {
+                let stack = number
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|n| n.checked_sub(1))
+                    .and_then(|i| Some((self.one_at_a_time.get(i)?, &self.all_at_once[i])))
+                    .ok_or_else(|| {
+                        format!(
+                            "there is no stack {}, expected 1 to {}",
+                            number,
+                            self.one_at_a_time.len()
+                        )
+                    })?;
+                // Listed bottom first, the way the drawing stacks them.
+                let listed = |stack: &VecDeque<char>| stack.iter().rev().collect::<String>();
+                Ok(format!(
+                    "CrateMover 9000: {}\nCrateMover 9001: {}",
+                    listed(stack.0),
+                    listed(stack.1)
+                ))
+            }
+            ["next"] => Ok(match self.input.moves.get(self.moves_made) {
+                Some(next) => next.to_string(),
+                None => "no moves left".to_owned(),
+            }),
+            _ => Err(format!("unknown query `{}`", words.join(" "))),
+        }
+    }
+
+    fn queries(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("top", "the crate on top of each stack, for each crane"),
+            (
+                "stack N",
+                "the crates in a stack from the bottom up, for each crane",
+            ),
+            ("next", "the move the next step makes"),
+        ]
+    }
+}
+
+#[test]
+fn test_explore_cranes() {
+    let input = crate::parse_input(include_str!("../example.txt")).unwrap();
+    let mut cranes = Day5::explore(&input, &Params::defaults(&[]));
+    assert_eq!(cranes.query(&["next"]).as_deref(), Ok("move 1 from 2 to 1"));
+    assert_eq!(
+        cranes.show(&Palette::mono()),
+        "CrateMover 9000\n    [D]\n[N] [C]\n[Z] [M] [P]\n 1   2   3 \n\n\
+         CrateMover 9001\n    [D]\n[N] [C]\n[Z] [M] [P]\n 1   2   3 "
+    );
+    assert_eq!(
+        cranes.step().as_deref(),
+        Some("move 1 from 2 to 1 (1 of 4)")
+    );
+    assert_eq!(
+        cranes.step().as_deref(),
+        Some("move 3 from 1 to 3 (2 of 4)")
+    );
+    assert_eq!(
+        cranes.query(&["stack", "3"]).as_deref(),
+        Ok("CrateMover 9000: PDNZ\nCrateMover 9001: PZND")
+    );
+    assert!(cranes.query(&["stack", "4"]).is_err());
+    while cranes.step().is_some() {}
+    assert_eq!(
+        cranes.query(&["top"]).as_deref(),
+        Ok("CrateMover 9000: CMZ\nCrateMover 9001: MCD")
+    );
+    assert_eq!(cranes.query(&["next"]).as_deref(), Ok("no moves left"));
+}
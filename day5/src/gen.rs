@@ -0,0 +1,57 @@
+use aoc_common::{Generate, Rng};
+
+use crate::Day5;
+
+/// `size` is the number of moves. There are always nine stacks, and every move takes at most
+/// as many crates as its stack holds.
+impl Generate for Day5 {
+    const DEFAULT_SIZE: usize = 500;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut stacks: Vec<Vec<char>> = (0..9)
+            .map(|_| {
+                (0..rng.range(1..=8))
+                    .map(|_| (b'A' + rng.below(26) as u8) as char)
+                    .collect()
+            })
+            .collect();
+        let tallest = stacks.iter().map(|stack| stack.len()).max().unwrap_or(0);
+
+        let mut out = String::new();
+        for level in (0..tallest).rev() {
+            let row: Vec<String> = stacks
+                .iter()
+                .map(|stack| match stack.get(level) {
+                    Some(c) => format!("[{}]", c),
+                    None => "   ".to_owned(),
+                })
+                .collect();
+            out.push_str(row.join(" ").trim_end());
+            out.push('\n');
+        }
+        let labels: Vec<String> = (1..=stacks.len()).map(|i| format!(" {} ", i)).collect();
+        out.push_str(&labels.join(" "));
+        out.push_str("\n\n");
+
+        for _ in 0..size {
+            let from = loop {
+                let from = rng.index(stacks.len());
+                if !stacks[from].is_empty() {
+                    break from;
+                }
+            };
+            let to = (from + 1 + rng.index(stacks.len() - 1)) % stacks.len();
+            let amount = rng.range(1..=stacks[from].len() as i64) as usize;
+            let split = stacks[from].len() - amount;
+            let moved = stacks[from].split_off(split);
+            stacks[to].extend(moved);
+            out.push_str(&format!(
+                "move {} from {} to {}\n",
+                amount,
+                from + 1,
+                to + 1
+            ));
+        }
+        out
+    }
+}
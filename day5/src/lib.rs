@@ -0,0 +1,207 @@
+//! Day 5: Supply Stacks, moving crates between stacks with two different cranes.
+
+pub mod explore;
+pub mod gen;
+
+use std::{collections::VecDeque, fmt::Display};
+
+use aoc_common::{lines, parse::Line, Params, ParseError, Solution};
+use regex::Regex;
+
+/// The starting stacks and the moves to make on them.
+#[derive(Debug)]
+pub struct Input {
+    /// Each stack's crates, top first.
+    pub stacks: Vec<VecDeque<char>>,
+    pub moves: Vec<Move>,
+}
+
+/// Moves `amount` crates between two stacks, numbered from zero.
+#[derive(Debug)]
+pub struct Move {
+    pub amount: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+impl Display for Move {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "move {} from {} to {}",
+            self.amount,
+            self.from + 1,
+            self.to + 1
+        )
+    }
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    type Input = Input;
+    type Output1 = String;
+    type Output2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input, _: &Params) -> Self::Output1 {
+        solution_1(input)
+    }
+
+    fn part_2(input: &Self::Input, _: &Params) -> Self::Output2 {
+        solution_2(input)
+    }
+}
+
+/// Reads the drawing of the stacks, a blank line, then `move N from A to B` lines.
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
+    let mut lines = lines(input);
+    let stack_lines: Vec<Line> = lines.by_ref().take_while(|line| !line.is_empty()).collect();
+    let move_lines: Vec<Line> = lines.collect();
+    let stacks = parse_stacks(&stack_lines)?;
+    let heights = stacks.iter().map(VecDeque::len).collect();
+    let moves = parse_moves(&move_lines, heights)?;
+    Ok(Input { stacks, moves })
+}
+
+fn parse_stacks(lines: &[Line]) -> Result<Vec<VecDeque<char>>, ParseError> {
+    let last = lines
+        .last()
+        .ok_or(ParseError::new(1, 1, "stack lines cannot be emtpy"))?;
+
+    let mut result = Vec::new();
+    for (i, char) in last.text.chars().enumerate() {
+        if !char.is_whitespace() {
+            let mut stack = VecDeque::new();
+            for line in lines.iter().rev().skip(1) {
+                let char = line.text.chars().nth(i).unwrap_or(' ');
+                if !char.is_whitespace() {
+                    stack.push_front(char)
+                }
+            }
+            result.push(stack);
+        }
+    }
+    Ok(result)
+}
+
+/// Both cranes move the same number of crates between the same stacks, so the stack
+/// `heights` are tracked here to reject moves that lift more crates than there are.
+fn parse_moves(lines: &[Line], mut heights: Vec<usize>) -> Result<Vec<Move>, ParseError> {
+    let stack_count = heights.len();
+    let re = Regex::new(r"^move (\d+) from (\d+) to (\d+)$").expect("valid regex");
+    let parse_stack = |line: &Line, number: &str| -> Result<usize, ParseError> {
+        match line.parse_at::<usize>(number)? {
+            n @ 1.. if n <= stack_count => Ok(n - 1),
+            _ => Err(line.error_at(
+                number,
+                format!(
+                    "there is no stack {}, expected 1 to {}",
+                    number, stack_count
+                ),
+            )),
+        }
+    };
+    lines
+        .iter()
+        .map(|line| {
+            let captures = re
+                .captures(line.text)
+                .ok_or_else(|| line.error(1, format!("invalid move line: {}", line.text)))?;
+            let capture = |i| captures.get(i).unwrap().as_str();
+            let amount = line.parse_at(capture(1))?;
+            let from = parse_stack(line, capture(2))?;
+            let to = parse_stack(line, capture(3))?;
+            if amount > heights[from] {
+                return Err(line.error_at(
+                    capture(1),
+                    format!("stack {} only holds {} crates", from + 1, heights[from]),
+                ));
+            }
+            heights[from] -= amount;
+            heights[to] += amount;
+            Ok(Move { amount, from, to })
+        })
+        .collect()
+}
+
+/// The top crates after moving crates one at a time. Empty stacks show as a space.
+pub fn solution_1(input: &Input) -> String {
+    let stacks_after_moves = run_moves_solution_1(input);
+    stacks_after_moves
+        .iter()
+        .map(|stack| stack.front().unwrap_or(&' '))
+        .collect()
+}
+
+fn run_moves_solution_1(input: &Input) -> Vec<VecDeque<char>> {
+    let mut result = input.stacks.clone();
+    for current_move in input.moves.iter() {
+        move_one_at_a_time(&mut result, current_move);
+    }
+    result
+}
+
+/// Makes a move with the CrateMover 9000, which lifts one crate at a time.
+pub fn move_one_at_a_time(stacks: &mut [VecDeque<char>], current_move: &Move) {
+    for _ in 0..current_move.amount {
+        if let Some(c) = stacks[current_move.from].pop_front() {
+            stacks[current_move.to].push_front(c)
+        }
+    }
+}
+
+/// The top crates after moving each batch of crates at once.
+pub fn solution_2(input: &Input) -> String {
+    let stacks_after_moves = run_moves_solution_2(input);
+    stacks_after_moves
+        .iter()
+        .map(|stack| stack.front().unwrap_or(&' '))
+        .collect()
+}
+
+fn run_moves_solution_2(input: &Input) -> Vec<VecDeque<char>> {
+    let mut result = input.stacks.clone();
+    for current_move in input.moves.iter() {
+        move_all_at_once(&mut result, current_move);
+    }
+    result
+}
+
+/// Makes a move with the CrateMover 9001, which lifts all the crates at once.
+pub fn move_all_at_once(stacks: &mut [VecDeque<char>], current_move: &Move) {
+    let mut intermediate_stack = VecDeque::new();
+    for _ in 0..current_move.amount {
+        if let Some(c) = stacks[current_move.from].pop_front() {
+            intermediate_stack.push_front(c);
+        }
+    }
+    for c in intermediate_stack {
+        stacks[current_move.to].push_front(c);
+    }
+}
+
+#[test]
+fn test_move_more_crates_than_the_stack_holds() {
+    let stacks = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\n";
+    assert_eq!(
+        Day5::parse(&format!(
+            "{}move 18446744073709551615 from 2 to 1\n",
+            stacks
+        ))
+        .unwrap_err(),
+        ParseError::new(6, 6, "stack 2 only holds 3 crates")
+    );
+    assert_eq!(
+        Day5::parse(&format!(
+            "{}move 3 from 2 to 1\nmove 6 from 1 to 3\n",
+            stacks
+        ))
+        .unwrap_err(),
+        ParseError::new(7, 6, "stack 1 only holds 5 crates")
+    );
+}
@@ -0,0 +1,20 @@
+use aoc_common::{Generate, Rng};
+
+use crate::Day6;
+
+/// `size` is the length of the datastream. Letters are drawn from a small alphabet so markers
+/// are rare, and one start-of-message marker is always planted in the second half.
+impl Generate for Day6 {
+    const DEFAULT_SIZE: usize = 4096;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut stream: Vec<u8> = (0..size.max(28))
+            .map(|_| b'a' + rng.below(12) as u8)
+            .collect();
+        let mut marker: Vec<u8> = (b'a'..=b'z').collect();
+        rng.shuffle(&mut marker);
+        let start = stream.len() / 2 + rng.index(stream.len() / 2 - 13);
+        stream[start..start + 14].copy_from_slice(&marker[..14]);
+        String::from_utf8(stream).expect("ascii letters") + "\n"
+    }
+}
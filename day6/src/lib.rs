@@ -0,0 +1,112 @@
+//! Day 6: Tuning Trouble, finding markers of distinct characters in a datastream.
+
+pub mod gen;
+
+use std::{collections::HashSet, hash::Hash};
+
+use aoc_common::{Param, Params, ParseError, Solution};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    type Input = String;
+    type Output1 = Option<usize>;
+    type Output2 = Option<usize>;
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "packet",
+            default: 4,
+            max: usize::MAX as u64,
+            help: "how many different characters make a start-of-packet marker",
+        },
+        Param {
+            name: "message",
+            default: 14,
+            max: usize::MAX as u64,
+            help: "how many different characters make a start-of-message marker",
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input, params: &Params) -> Self::Output1 {
+        first_occurance_of_unique_seq_of_len(input, params.value::<usize>("packet"))
+    }
+
+    fn part_2(input: &Self::Input, params: &Params) -> Self::Output2 {
+        first_occurance_of_unique_seq_of_len(input, params.value::<usize>("message"))
+    }
+}
+
+/// Takes the first line of the input as the datastream.
+pub fn parse_input(input: &str) -> Result<String, ParseError> {
+    input
+        .lines()
+        .next()
+        .map(|line| line.to_owned())
+        .ok_or(ParseError::new(1, 1, "expected a datastream"))
+}
+
+/// Where the first start-of-packet marker ends.
+pub fn solution_1(input: &str) -> Option<usize> {
+    first_occurance_of_unique_seq_of_len(input, 4)
+}
+
+/// Where the first start-of-message marker ends.
+pub fn solution_2(input: &str) -> Option<usize> {
+    first_occurance_of_unique_seq_of_len(input, 14)
+}
+
+/// How many characters must be read before the last `len` are all different.
+pub fn first_occurance_of_unique_seq_of_len(message: &str, len: usize) -> Option<usize> {
+    let window_size = len;
+    if window_size == 0 {
+        return Some(0);
+    }
+    let chars: Vec<char> = message.chars().collect();
+    for (i, window) in chars.windows(window_size).enumerate() {
+        if has_unique_elems(window) {
+            return Some(i + window_size);
+        }
+    }
+    None
+}
+
+fn has_unique_elems<T: Hash + Eq>(v: &[T]) -> bool {
+    HashSet::<&T>::from_iter(v.iter()).len() == v.len()
+}
+
+#[cfg(test)]
+use proptest::prelude::*;
+
+/// Checks every pair of characters in every window rather than hashing.
+#[cfg(test)]
+fn reference_marker_end(message: &str, len: usize) -> Option<usize> {
+    let chars: Vec<char> = message.chars().collect();
+    (len..=chars.len()).find(|&end| {
+        let window = &chars[end - len..end];
+        (0..len).all(|i| (i + 1..len).all(|j| window[i] != window[j]))
+    })
+}
+
+#[cfg(test)]
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(2000))]
+    #[test]
+    fn test_marker_matches_reference(message in "[a-p]{0,40}", len in 1..=16usize) {
+        prop_assert_eq!(
+            first_occurance_of_unique_seq_of_len(&message, len),
+            reference_marker_end(&message, len)
+        );
+    }
+
+    #[test]
+    fn test_solutions_match_reference(message in "[a-p]{1,60}") {
+        let input = parse_input(&message).unwrap();
+        prop_assert_eq!(solution_1(&input), reference_marker_end(&message, 4));
+        prop_assert_eq!(solution_2(&input), reference_marker_end(&message, 14));
+    }
+}
@@ -0,0 +1,174 @@
+use std::{cell::RefCell, rc::Rc};
+
+use aoc_common::{
+    explore::{Exploration, Explore},
+    vis::{Palette, Role},
+    Params,
+};
+
+use crate::{Day7, Directory};
+
+/// Walks the tree depth first, entering one directory per step.
+struct TreeExploration {
+    /// Every directory with its full path, in the order they are walked.
+    directories: Vec<(String, Rc<RefCell<Directory>>)>,
+    current: usize,
+    total_space: usize,
+    needed_space: usize,
+}
+
+impl Explore for Day7 {
+    fn explore<'a>(input: &'a Self::Input, params: &Params) -> Box<dyn Exploration + 'a> {
+        let mut directories = Vec::new();
+        walk("/".to_owned(), input, &mut directories);
+        Box::new(TreeExploration {
+            directories,
+            current: 0,
+            total_space: params.value::<usize>("total_space"),
+            needed_space: params.value::<usize>("needed_space"),
+        })
+    }
+}
+
+fn walk(
+    path: String,
+    directory: &Rc<RefCell<Directory>>,
+    directories: &mut Vec<(String, Rc<RefCell<Directory>>)>,
+) {
+    // An explicit stack, pushed in reverse to keep the listed order, so deep trees fit.
+    let mut stack = vec![(path, Rc::clone(directory))];
+    while let Some((path, directory)) = stack.pop() {
+        for subdirectory in directory.borrow().subdirectories().iter().rev() {
+            let name = subdirectory.borrow().name().to_owned();
+            stack.push((join(&path, &name), Rc::clone(subdirectory)));
+        }
+        directories.push((path, directory));
+    }
+}
+
+fn join(path: &str, name: &str) -> String {
+    format!("{}/{}", path.trim_end_matches('/'), name)
+}
+
+impl TreeExploration {
+    fn find(&self, path: &str) -> Option<&Rc<RefCell<Directory>>> {
+        let path = if path.starts_with('/') {
+            path.to_owned()
+        } else {
+            join(&self.directories[self.current].0, path)
+        };
+        let path = match path.trim_end_matches('/') {
+            "" => "/",
+            path => path,
+        };
+        self.directories
+            .iter()
+            .find(|(p, _)| p == path)
+            .map(|(_, directory)| directory)
+    }
+}
+
+impl Exploration for TreeExploration {
+    fn step(&mut self) -> Option<String> {
+        if self.current + 1 >= self.directories.len() {
+            return None;
+        }
+        self.current += 1;
+        let (path, directory) = &self.directories[self.current];
+        Some(format!("entered {} ({})", path, directory.borrow().size()))
+    }
+
+    fn show(&self, palette: &Palette) -> String {
+        let (path, directory) = &self.directories[self.current];
+        let directory = directory.borrow();
+        let mut lines =
+            vec![palette.paint(&format!("{} ({})", path, directory.size()), Role::Highlight)];
+        for subdirectory in directory.subdirectories() {
+            let subdirectory = subdirectory.borrow();
+            lines.push(palette.paint(
+                &format!("dir {} ({})", subdirectory.name(), subdirectory.size()),
+                Role::Trail,
+            ));
+        }
+        for (name, size) in directory.files() {
+            lines.push(format!("{} {}", size, name));
+        }
+        lines.join("\n")
+    }
+
+    fn query(&self, words: &[&str]) -> Result<String, String> {
+        match words {
+            ["size", path] => self
+                .find(path)
+                .map(|directory| directory.borrow().size().to_string())
+                .ok_or_else(|| format!("there is no directory `{}`", path)),
+            ["under", max] => {
+                let max: usize = max
+                    .parse()
+                    .map_err(|_| format!("expected a size, not `{}`", max))?;
+                Ok(self
+                    .directories
+                    .iter()
+                    .map(|(path, directory)| (path, directory.borrow().size()))
+                    .filter(|&(_, size)| size <= max)
+                    .map(|(path, size)| format!("{} {}", path, size))
+                    .collect::<Vec<_>>()
+                    .join("\n"))
+            }
+            ["free"] => {
+                let used = self.directories[0].1.borrow().size();
+                let free = self.total_space.saturating_sub(used);
+                Ok(format!(
+                    "{} of {} used, {} free, {} more needed",
+                    used,
+                    self.total_space,
+                    free,
+                    self.needed_space.saturating_sub(free)
+                ))
+            }
+            _ => Err(format!("unknown query `{}`", words.join(" "))),
+        }
+    }
+
+    fn queries(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            (
+                "size PATH",
+                "the size of a directory, from / or the current one",
+            ),
+            ("under SIZE", "every directory no bigger than SIZE"),
+            (
+                "free",
+                "how much of the disk is used and how much is needed",
+            ),
+        ]
+    }
+}
+
+#[test]
+fn test_explore_tree() {
+    use aoc_common::Solution;
+
+    let top_dir = Day7::parse(include_str!("../example.txt")).unwrap();
+    let mut tree = Day7::explore(&top_dir, &Params::defaults(Day7::PARAMS));
+    assert_eq!(
+        tree.show(&Palette::mono()),
+        "/ (48381165)\ndir a (94853)\ndir d (24933642)\n14848514 b.txt\n8504156 c.dat"
+    );
+    assert_eq!(tree.step().as_deref(), Some("entered /a (94853)"));
+    assert_eq!(tree.query(&["size", "e"]).as_deref(), Ok("584"));
+    assert_eq!(tree.query(&["size", "/d/"]).as_deref(), Ok("24933642"));
+    assert_eq!(tree.query(&["size", "/"]).as_deref(), Ok("48381165"));
+    assert!(tree.query(&["size", "/x"]).is_err());
+    assert_eq!(
+        tree.query(&["under", "100000"]).as_deref(),
+        Ok("/a 94853\n/a/e 584")
+    );
+    assert_eq!(
+        tree.query(&["free"]).as_deref(),
+        Ok("48381165 of 70000000 used, 21618835 free, 8381165 more needed")
+    );
+    assert_eq!(tree.step().as_deref(), Some("entered /a/e (584)"));
+    assert_eq!(tree.step().as_deref(), Some("entered /d (24933642)"));
+    assert_eq!(tree.step(), None);
+}
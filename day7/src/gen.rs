@@ -0,0 +1,58 @@
+use aoc_common::{Generate, Rng};
+
+use crate::Day7;
+
+struct Dir {
+    name: String,
+    files: Vec<(usize, String)>,
+    children: Vec<usize>,
+}
+
+/// `size` is the number of directories, including `/`. The transcript visits every directory
+/// once, listing it before descending into its subdirectories.
+impl Generate for Day7 {
+    const DEFAULT_SIZE: usize = 180;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut dirs = vec![Dir {
+            name: "/".to_owned(),
+            files: Vec::new(),
+            children: Vec::new(),
+        }];
+        for i in 1..size.max(1) {
+            let parent = rng.index(i);
+            let name = format!("{}{}", rng.word(1..=6), i);
+            dirs[parent].children.push(i);
+            dirs.push(Dir {
+                name,
+                files: Vec::new(),
+                children: Vec::new(),
+            });
+        }
+        for dir in dirs.iter_mut() {
+            for j in 0..rng.range(0..=4) {
+                let name = format!("{}{}.{}", rng.word(1..=8), j, rng.word(1..=3));
+                dir.files.push((rng.range(1000..=300000) as usize, name));
+            }
+        }
+
+        let mut out = String::new();
+        write_dir(&dirs, 0, &mut out);
+        out
+    }
+}
+
+fn write_dir(dirs: &[Dir], index: usize, out: &mut String) {
+    let dir = &dirs[index];
+    out.push_str(&format!("$ cd {}\n$ ls\n", dir.name));
+    for &child in dir.children.iter() {
+        out.push_str(&format!("dir {}\n", dirs[child].name));
+    }
+    for (size, name) in dir.files.iter() {
+        out.push_str(&format!("{} {}\n", size, name));
+    }
+    for &child in dir.children.iter() {
+        write_dir(dirs, child, out);
+        out.push_str("$ cd ..\n");
+    }
+}
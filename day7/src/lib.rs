@@ -0,0 +1,366 @@
+//! Day 7: No Space Left On Device, sizing directories from a terminal transcript.
+
+pub mod explore;
+pub mod gen;
+
+use std::cell::RefCell;
+use std::fmt::Debug;
+use std::rc::Weak;
+use std::{rc::Rc, vec};
+
+use aoc_common::{lines, Param, Params, ParseError, Solution};
+
+/// A directory rebuilt from the transcript, shared so subdirectories can point back up.
+#[derive(Debug)]
+pub struct Directory {
+    name: String,
+    files: Vec<File>,
+    parent: Option<Weak<RefCell<Directory>>>,
+    subdirectories: Vec<Rc<RefCell<Directory>>>,
+}
+
+#[derive(Debug)]
+struct File {
+    name: String,
+    size: usize,
+}
+
+/// One line of the transcript.
+#[derive(Debug)]
+pub enum LineToken {
+    CD(String),
+    CDOut,
+    LS,
+    Dir { name: String },
+    File { size: usize, name: String },
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    type Input = Rc<RefCell<Directory>>;
+    type Output1 = usize;
+    type Output2 = Option<usize>;
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "total_space",
+            default: 70000000,
+            max: usize::MAX as u64,
+            help: "the size of the device's disk",
+        },
+        Param {
+            name: "needed_space",
+            default: 30000000,
+            max: usize::MAX as u64,
+            help: "how much free space the update needs",
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_filesystem(&parse_input(input)?)
+    }
+
+    fn part_1(input: &Self::Input, _: &Params) -> Self::Output1 {
+        solution_1(input)
+    }
+
+    fn part_2(input: &Self::Input, params: &Params) -> Self::Output2 {
+        solution_2(
+            input,
+            params.value::<usize>("total_space"),
+            params.value::<usize>("needed_space"),
+        )
+    }
+}
+
+/// Reads the transcript line by line. Use [`parse_filesystem`] to rebuild the tree.
+pub fn parse_input(input: &str) -> Result<Vec<LineToken>, ParseError> {
+    lines(input)
+        .map(
+            |line| match line.text.split(' ').collect::<Vec<&str>>().as_slice() {
+                ["$", "ls"] => Ok(LineToken::LS),
+                ["$", "cd", ".."] => Ok(LineToken::CDOut),
+                ["$", "cd", dirname] => Ok(LineToken::CD(dirname.to_string())),
+                ["dir", dirname] => Ok(LineToken::Dir {
+                    name: dirname.to_string(),
+                }),
+                [size, filename] if !size.starts_with('$') => Ok(LineToken::File {
+                    size: line.parse_at(size)?,
+                    name: filename.to_string(),
+                }),
+                _ => Err(line.error(1, format!("unparsable line: {}", line.text))),
+            },
+        )
+        .collect()
+}
+
+/// Sums the sizes of all directories smaller than 100000.
+pub fn solution_1(top_dir: &Rc<RefCell<Directory>>) -> usize {
+    directory_sizes(top_dir)
+        .into_iter()
+        .map(|(_, size)| size)
+        .filter(|size| *size < 100000)
+        .sum()
+}
+
+/// The size of the smallest directory that frees `needed_space` on a disk of `total_space`.
+pub fn solution_2(
+    top_dir: &Rc<RefCell<Directory>>,
+    total_space: usize,
+    needed_space: usize,
+) -> Option<usize> {
+    let mut sizes: Vec<usize> = directory_sizes(top_dir)
+        .into_iter()
+        .map(|(_, size)| size)
+        .collect();
+    sizes.sort();
+    let space_used: usize = *sizes.iter().last().unwrap();
+    sizes
+        .iter()
+        .find(|size| {
+            total_space
+                .checked_sub(space_used.saturating_sub(**size))
+                .map(|space_left| space_left >= needed_space)
+                .unwrap_or(false)
+        })
+        .cloned()
+}
+
+/// `top_dir` and every directory below it with its size, in breadth-first order.
+///
+/// Each directory's files are added once and then passed up to its parent, children
+/// before parents, so deep trees take neither quadratic time nor a deep stack.
+pub fn directory_sizes(top_dir: &Rc<RefCell<Directory>>) -> Vec<(Rc<RefCell<Directory>>, usize)> {
+    let mut directories: Vec<(Option<usize>, Rc<RefCell<Directory>>)> =
+        vec![(None, Rc::clone(top_dir))];
+    let mut i = 0;
+    while i < directories.len() {
+        let subdirectories = directories[i].1.borrow().subdirectories.clone();
+        directories.extend(subdirectories.into_iter().map(|dir| (Some(i), dir)));
+        i += 1;
+    }
+    let mut sizes: Vec<usize> = directories
+        .iter()
+        .map(|(_, dir)| dir.borrow().files_size())
+        .collect();
+    for (i, (parent, _)) in directories.iter().enumerate().rev() {
+        if let Some(parent) = parent {
+            sizes[*parent] += sizes[i];
+        }
+    }
+    directories
+        .into_iter()
+        .map(|(_, dir)| dir)
+        .zip(sizes)
+        .collect()
+}
+
+impl Directory {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The total size of the files in this directory and all its subdirectories.
+    pub fn size(&self) -> usize {
+        let mut size = self.files_size();
+        let mut stack = self.subdirectories.clone();
+        while let Some(dir) = stack.pop() {
+            let dir = dir.borrow();
+            size += dir.files_size();
+            stack.extend(dir.subdirectories.iter().cloned());
+        }
+        size
+    }
+
+    fn files_size(&self) -> usize {
+        self.files.iter().map(|file| file.size).sum()
+    }
+
+    pub fn subdirectories(&self) -> &[Rc<RefCell<Directory>>] {
+        &self.subdirectories
+    }
+
+    /// The name and size of each file directly in this directory.
+    pub fn files(&self) -> impl Iterator<Item = (&str, usize)> {
+        self.files
+            .iter()
+            .map(|file| (file.name.as_str(), file.size))
+    }
+}
+
+impl Drop for Directory {
+    /// Takes nested directories apart one at a time, as dropping them recursively would
+    /// overflow the stack on a deep tree.
+    fn drop(&mut self) {
+        let mut stack = std::mem::take(&mut self.subdirectories);
+        while let Some(dir) = stack.pop() {
+            if let Ok(dir) = Rc::try_unwrap(dir) {
+                stack.append(&mut dir.into_inner().subdirectories);
+            }
+        }
+    }
+}
+
+/// Rebuilds the directory tree the transcript explores, returning `/`.
+pub fn parse_filesystem(input: &[LineToken]) -> Result<Rc<RefCell<Directory>>, ParseError> {
+    let mut lines = input.iter().enumerate().map(|(i, line)| (i + 1, line));
+    let _first_cd = lines.next();
+    let top_level_dir = Rc::new(RefCell::new(Directory {
+        name: "/".to_owned(),
+        parent: None,
+        files: vec![],
+        subdirectories: vec![],
+    }));
+    let mut current = Rc::clone(&top_level_dir);
+    // Every directory's size is at most the sum of all files, so bounding it here keeps
+    // `Directory::size` from overflowing.
+    let mut total_size: usize = 0;
+    for (line_number, line) in lines {
+        match line {
+            LineToken::CDOut => {
+                let parent = current.borrow().parent.as_ref().and_then(|p| p.upgrade());
+                current = parent.ok_or(ParseError::new(
+                    line_number,
+                    1,
+                    "cannot cd out of the top level directory",
+                ))?;
+            }
+            LineToken::CD(dirname) => {
+                let new_curr = current
+                    .borrow()
+                    .subdirectories
+                    .iter()
+                    .find(|dir| dir.borrow().name == *dirname)
+                    .cloned()
+                    .ok_or_else(|| {
+                        ParseError::new(
+                            line_number,
+                            1,
+                            format!("cd into unknown directory `{}`", dirname),
+                        )
+                    })?;
+                current = new_curr;
+            }
+            LineToken::LS => {}
+            LineToken::File { name, size } => {
+                total_size = total_size.checked_add(*size).ok_or(ParseError::new(
+                    line_number,
+                    1,
+                    format!("files add up to more than {} bytes", usize::MAX),
+                ))?;
+                current.borrow_mut().files.push(File {
+                    name: name.clone(),
+                    size: *size,
+                });
+            }
+            LineToken::Dir { name } => {
+                let parent = Some(Rc::downgrade(&current));
+                current
+                    .borrow_mut()
+                    .subdirectories
+                    .push(Rc::new(RefCell::new(Directory {
+                        name: name.clone(),
+                        parent,
+                        files: vec![],
+                        subdirectories: vec![],
+                    })));
+            }
+        };
+    }
+    Ok(top_level_dir)
+}
+
+#[test]
+fn test_parse_filesystem() {
+    let lines = vec![
+        LineToken::CD("/".to_owned()),
+        LineToken::LS,
+        LineToken::Dir {
+            name: "a".to_owned(),
+        },
+        LineToken::File {
+            size: 14848514,
+            name: "b.txt".to_owned(),
+        },
+        LineToken::Dir {
+            name: "d".to_owned(),
+        },
+        LineToken::CD("a".to_owned()),
+        LineToken::LS,
+        LineToken::Dir {
+            name: "e".to_owned(),
+        },
+        LineToken::File {
+            size: 29116,
+            name: "f".to_owned(),
+        },
+        LineToken::File {
+            size: 2557,
+            name: "g".to_owned(),
+        },
+        LineToken::File {
+            size: 62596,
+            name: "h.lst".to_owned(),
+        },
+    ];
+    let result = parse_filesystem(&lines).unwrap();
+    {
+        let top_dir = result.borrow();
+        assert_eq!(top_dir.name, "/".to_owned());
+        let a = &top_dir.subdirectories[0];
+        let d = &top_dir.subdirectories[1];
+        assert_eq!(a.borrow().name, "a".to_owned());
+        assert_eq!(d.borrow().name, "d".to_owned());
+    }
+}
+
+#[test]
+fn test_parse_example_input() {
+    let input = include_str!("../example.txt");
+    let lines = parse_input(input).unwrap();
+    let result = parse_filesystem(&lines).unwrap();
+    let top_dir = result.borrow();
+    assert_eq!(top_dir.size(), 48381165);
+    let sizes: Vec<(String, usize)> = top_dir
+        .subdirectories
+        .iter()
+        .map(|dir| (dir.borrow().name.clone(), dir.borrow().size()))
+        .collect();
+    assert_eq!(
+        sizes,
+        vec![("a".to_owned(), 94853), ("d".to_owned(), 24933642)]
+    );
+    let e = &top_dir.subdirectories[0].borrow().subdirectories[0];
+    assert_eq!(e.borrow().name, "e");
+    assert_eq!(e.borrow().size(), 584);
+}
+
+#[test]
+fn test_deep_tree() {
+    let depth = 200000;
+    let input = format!("$ cd /\n{}1 f\n", "dir a\n$ cd a\n".repeat(depth));
+    let top_dir = Day7::parse(&input).unwrap();
+    assert_eq!(solution_1(&top_dir), depth + 1);
+    assert_eq!(top_dir.borrow().size(), 1);
+}
+
+#[test]
+fn test_parse_errors() {
+    assert_eq!(
+        Day7::parse("$ cd /\n$ ls\n12x a.txt\n").unwrap_err(),
+        ParseError::new(3, 1, "invalid number `12x`")
+    );
+    assert_eq!(
+        Day7::parse("$ cd /\n$ cd a\n").unwrap_err(),
+        ParseError::new(2, 1, "cd into unknown directory `a`")
+    );
+    assert_eq!(
+        Day7::parse("$ cd /\n18446744073709551615 a\n18446744073709551615 b\n").unwrap_err(),
+        ParseError::new(
+            3,
+            1,
+            format!("files add up to more than {} bytes", usize::MAX)
+        )
+    );
+}
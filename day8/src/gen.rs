@@ -0,0 +1,20 @@
+use aoc_common::{Generate, Rng};
+
+use crate::Day8;
+
+/// `size` is the width and height of the square forest.
+impl Generate for Day8 {
+    const DEFAULT_SIZE: usize = 99;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+        (0..size)
+            .map(|_| {
+                let row: String = (0..size)
+                    .map(|_| char::from_digit(rng.below(10) as u32, 10).unwrap())
+                    .collect();
+                row + "\n"
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,146 @@
+//! Day 8: Treetop Tree House, judging views across a grid of tree heights.
+
+pub mod gen;
+
+use aoc_common::{
+    grid::{self, Direction, Pos},
+    Params, ParseError, Solution,
+};
+
+/// Tree heights from 0 to 9.
+pub type Grid = grid::Grid<i32>;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    type Input = Grid;
+    type Output1 = i32;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input, _: &Params) -> Self::Output1 {
+        solution_1(input)
+    }
+
+    fn part_2(input: &Self::Input, _: &Params) -> Self::Output2 {
+        solution_2(input)
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Grid, ParseError> {
+    Grid::parse(input, |c| {
+        c.to_digit(10)
+            .map(|height| height as i32)
+            .ok_or(format!("invalid tree height `{}`", c))
+    })
+}
+
+/// Counts the trees visible from outside the grid.
+pub fn solution_1(grid: &Grid) -> i32 {
+    grid.positions()
+        .filter(|&pos| is_visible(pos, grid))
+        .count() as i32
+}
+
+/// The best scenic score of any tree.
+pub fn solution_2(grid: &Grid) -> usize {
+    grid.positions()
+        .map(|pos| scenic_score(pos, grid))
+        .max()
+        .unwrap()
+}
+
+fn scenic_score(pos: Pos, grid: &Grid) -> usize {
+    let value = grid[pos];
+    Direction::ORTHOGONAL
+        .iter()
+        .map(|&direction| {
+            let mut seen = 0;
+            for other in grid.ray(pos, direction) {
+                seen += 1;
+                if grid[other] >= value {
+                    break;
+                }
+            }
+            seen
+        })
+        .product()
+}
+
+fn is_visible(pos: Pos, grid: &Grid) -> bool {
+    let value = grid[pos];
+    Direction::ORTHOGONAL
+        .iter()
+        .any(|&direction| grid.ray(pos, direction).all(|other| grid[other] < value))
+}
+
+#[test]
+fn test_scenic_score() {
+    let input = include_str!("../example.txt");
+    let grid = parse_input(input).unwrap();
+    assert_eq!(scenic_score(Pos::new(0, 0), &grid), 0);
+    assert_eq!(scenic_score(Pos::new(1, 2), &grid), 4);
+    assert_eq!(scenic_score(Pos::new(3, 2), &grid), 8);
+}
+
+#[cfg(test)]
+use proptest::prelude::*;
+
+/// Both answers from plain index loops over the rows of digits.
+#[cfg(test)]
+fn reference_solutions(rows: &[Vec<u32>]) -> (i32, usize) {
+    let (height, width) = (rows.len(), rows[0].len());
+    let mut visible = 0;
+    let mut best_score = 0;
+    for row in 0..height {
+        for col in 0..width {
+            let tree = rows[row][col];
+            let lines_of_sight: [Vec<u32>; 4] = [
+                (0..row).rev().map(|r| rows[r][col]).collect(),
+                (row + 1..height).map(|r| rows[r][col]).collect(),
+                (0..col).rev().map(|c| rows[row][c]).collect(),
+                (col + 1..width).map(|c| rows[row][c]).collect(),
+            ];
+            if lines_of_sight
+                .iter()
+                .any(|line| line.iter().all(|&other| other < tree))
+            {
+                visible += 1;
+            }
+            let score: usize = lines_of_sight
+                .iter()
+                .map(|line| match line.iter().position(|&other| other >= tree) {
+                    Some(blocked) => blocked + 1,
+                    None => line.len(),
+                })
+                .product();
+            best_score = best_score.max(score);
+        }
+    }
+    (visible, best_score)
+}
+
+#[cfg(test)]
+fn forest_strategy() -> impl Strategy<Value = Vec<Vec<u32>>> {
+    (1..=8usize, 1..=8usize).prop_flat_map(|(width, height)| {
+        prop::collection::vec(prop::collection::vec(0..=9u32, width), height)
+    })
+}
+
+#[cfg(test)]
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(2000))]
+    #[test]
+    fn test_solutions_match_reference(rows in forest_strategy()) {
+        let text: String = rows
+            .iter()
+            .map(|row| row.iter().map(|h| h.to_string()).collect::<String>() + "\n")
+            .collect();
+        let grid = parse_input(&text).unwrap();
+        prop_assert_eq!((solution_1(&grid), solution_2(&grid)), reference_solutions(&rows));
+    }
+}
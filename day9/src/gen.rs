@@ -0,0 +1,20 @@
+use aoc_common::{Generate, Rng};
+
+use crate::Day9;
+
+/// `size` is the number of head motions.
+impl Generate for Day9 {
+    const DEFAULT_SIZE: usize = 2000;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|_| {
+                format!(
+                    "{} {}\n",
+                    rng.choose(&['U', 'D', 'L', 'R']),
+                    rng.range(1..=20)
+                )
+            })
+            .collect()
+    }
+}
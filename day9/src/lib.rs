@@ -0,0 +1,522 @@
+//! Day 9: Rope Bridge, following the knots of a rope as its head moves.
+
+pub mod gen;
+pub mod vis;
+
+use std::{
+    collections::HashSet,
+    iter::{repeat_n, successors},
+};
+
+use aoc_common::{lines, position::Point, Params, ParseError, Solution};
+
+#[derive(Debug, Clone, Copy)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+/// Moves the head `amount` steps in `direction`.
+#[derive(Debug)]
+pub struct Move {
+    pub direction: Direction,
+    pub amount: usize,
+}
+
+type Rope = Vec<Point>;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    type Input = Vec<Move>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_1(input: &Self::Input, _: &Params) -> Self::Output1 {
+        solution_1(input)
+    }
+
+    fn part_2(input: &Self::Input, _: &Params) -> Self::Output2 {
+        solution_2(input)
+    }
+}
+
+/// Reads lines like `R 4`. The head has to stay within `i32` coordinates.
+pub fn parse_input(input: &str) -> Result<Vec<Move>, ParseError> {
+    let mut head = (0i64, 0i64);
+    lines(input)
+        .map(|line| {
+            use Direction::*;
+            let (direction, amount_text) = line.split_once(" ")?;
+            let amount: usize = line.parse_at(amount_text)?;
+            let direction = match direction {
+                "U" => Up,
+                "D" => Down,
+                "L" => Left,
+                "R" => Right,
+                _ => {
+                    return Err(line.error_at(
+                        direction,
+                        format!("expected U, D, L or R, found `{}`", direction),
+                    ))
+                }
+            };
+            let distance = i64::try_from(amount).unwrap_or(i64::MAX);
+            head = match direction {
+                Up => (head.0, head.1.saturating_add(distance)),
+                Down => (head.0, head.1.saturating_sub(distance)),
+                Right => (head.0.saturating_add(distance), head.1),
+                Left => (head.0.saturating_sub(distance), head.1),
+            };
+            if i32::try_from(head.0).is_err() || i32::try_from(head.1).is_err() {
+                return Err(line.error_at(
+                    amount_text,
+                    "the head would move outside the range of `i32` coordinates",
+                ));
+            }
+            Ok(Move { direction, amount })
+        })
+        .collect()
+}
+
+/// Counts the positions the tail of a two-knot rope visits.
+pub fn solution_1(moves: &[Move]) -> usize {
+    count_tail_positions(moves, &vec![Point::default(); 2])
+}
+
+/// Counts the positions the tail of a ten-knot rope visits.
+pub fn solution_2(moves: &[Move]) -> usize {
+    count_tail_positions(moves, &vec![Point::default(); 10])
+}
+
+fn count_tail_positions(moves: &[Move], rope: &Rope) -> usize {
+    steps(moves, rope)
+        .fold(HashSet::new(), |mut tail_positions, step| {
+            tail_positions.insert(*step.last().unwrap());
+            tail_positions
+        })
+        .len()
+}
+
+/// The rope after every step, starting with `rope` itself.
+fn steps<'a>(moves: &'a [Move], rope: &Rope) -> impl Iterator<Item = Rope> + 'a {
+    let mut directions = moves.iter().flat_map(unit_moves);
+    successors(Some(rope.clone()), move |current_rope| {
+        let current_direction = directions.next()?;
+        Some(step(&current_direction, current_rope))
+    })
+}
+
+fn unit_moves(m: &Move) -> impl Iterator<Item = Direction> {
+    repeat_n(m.direction, m.amount)
+}
+
+fn step(current_move: &Direction, rope: &Rope) -> Rope {
+    use Direction::*;
+    let head = *rope.first().unwrap();
+    let next_head = match current_move {
+        Up => Point::new(head.x, head.y + 1),
+        Down => Point::new(head.x, head.y - 1),
+        Right => Point::new(head.x + 1, head.y),
+        Left => Point::new(head.x - 1, head.y),
+    };
+    let mut next_rope = rope.clone();
+    next_rope[0] = next_head;
+    for i in 0..next_rope.len() - 1 {
+        let fst = next_rope[i];
+        let snd = next_rope[i + 1];
+        // A knot left behind steps straight or diagonally towards the one ahead of it.
+        if !fst.is_touching(&snd) {
+            next_rope[i + 1] = Point::new(
+                snd.x + (fst.x - snd.x).signum(),
+                snd.y + (fst.y - snd.y).signum(),
+            );
+        }
+    }
+    next_rope
+}
+
+#[test]
+fn test_solution_2_example_steps() {
+    let example_txt = include_str!("../example.txt");
+    let moves = parse_input(example_txt).unwrap();
+    let row_col_steps: Vec<Vec<(i32, i32)>> = steps(&moves, &vec![Point::default(); 10])
+        .map(|rope| rope.iter().map(|point| (point.y, point.x)).collect())
+        .collect();
+    assert_eq!(
+        row_col_steps,
+        vec![
+            vec![
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0)
+            ],
+            vec![
+                (0, 1),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0)
+            ],
+            vec![
+                (0, 2),
+                (0, 1),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0)
+            ],
+            vec![
+                (0, 3),
+                (0, 2),
+                (0, 1),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0)
+            ],
+            vec![
+                (0, 4),
+                (0, 3),
+                (0, 2),
+                (0, 1),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0)
+            ],
+            vec![
+                (1, 4),
+                (0, 3),
+                (0, 2),
+                (0, 1),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0)
+            ],
+            vec![
+                (2, 4),
+                (1, 4),
+                (1, 3),
+                (1, 2),
+                (1, 1),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0)
+            ],
+            vec![
+                (3, 4),
+                (2, 4),
+                (1, 3),
+                (1, 2),
+                (1, 1),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0)
+            ],
+            vec![
+                (4, 4),
+                (3, 4),
+                (2, 4),
+                (2, 3),
+                (2, 2),
+                (1, 1),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0)
+            ],
+            vec![
+                (4, 3),
+                (3, 4),
+                (2, 4),
+                (2, 3),
+                (2, 2),
+                (1, 1),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0)
+            ],
+            vec![
+                (4, 2),
+                (4, 3),
+                (3, 3),
+                (2, 3),
+                (2, 2),
+                (1, 1),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0)
+            ],
+            vec![
+                (4, 1),
+                (4, 2),
+                (3, 3),
+                (2, 3),
+                (2, 2),
+                (1, 1),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0)
+            ],
+            vec![
+                (3, 1),
+                (4, 2),
+                (3, 3),
+                (2, 3),
+                (2, 2),
+                (1, 1),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0)
+            ],
+            vec![
+                (3, 2),
+                (4, 2),
+                (3, 3),
+                (2, 3),
+                (2, 2),
+                (1, 1),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0)
+            ],
+            vec![
+                (3, 3),
+                (4, 2),
+                (3, 3),
+                (2, 3),
+                (2, 2),
+                (1, 1),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0)
+            ],
+            vec![
+                (3, 4),
+                (3, 3),
+                (3, 3),
+                (2, 3),
+                (2, 2),
+                (1, 1),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0)
+            ],
+            vec![
+                (3, 5),
+                (3, 4),
+                (3, 3),
+                (2, 3),
+                (2, 2),
+                (1, 1),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0)
+            ],
+            vec![
+                (2, 5),
+                (3, 4),
+                (3, 3),
+                (2, 3),
+                (2, 2),
+                (1, 1),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0)
+            ],
+            vec![
+                (2, 4),
+                (3, 4),
+                (3, 3),
+                (2, 3),
+                (2, 2),
+                (1, 1),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0)
+            ],
+            vec![
+                (2, 3),
+                (3, 4),
+                (3, 3),
+                (2, 3),
+                (2, 2),
+                (1, 1),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0)
+            ],
+            vec![
+                (2, 2),
+                (2, 3),
+                (3, 3),
+                (2, 3),
+                (2, 2),
+                (1, 1),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0)
+            ],
+            vec![
+                (2, 1),
+                (2, 2),
+                (3, 3),
+                (2, 3),
+                (2, 2),
+                (1, 1),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0)
+            ],
+            vec![
+                (2, 0),
+                (2, 1),
+                (2, 2),
+                (2, 3),
+                (2, 2),
+                (1, 1),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0)
+            ],
+            vec![
+                (2, 1),
+                (2, 1),
+                (2, 2),
+                (2, 3),
+                (2, 2),
+                (1, 1),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0)
+            ],
+            vec![
+                (2, 2),
+                (2, 1),
+                (2, 2),
+                (2, 3),
+                (2, 2),
+                (1, 1),
+                (0, 0),
+                (0, 0),
+                (0, 0),
+                (0, 0)
+            ]
+        ]
+    );
+}
+
+#[cfg(test)]
+use proptest::prelude::*;
+
+/// Simulates the rules as the puzzle states them: a knot that falls behind steps once
+/// straight or diagonally towards the knot ahead of it.
+#[cfg(test)]
+fn reference_tail_positions(moves: &[(char, usize)], knots: usize) -> usize {
+    let mut rope = vec![(0i32, 0i32); knots];
+    let mut visited = HashSet::from([(0, 0)]);
+    for &(direction, amount) in moves {
+        let (dx, dy) = match direction {
+            'U' => (0, 1),
+            'D' => (0, -1),
+            'L' => (-1, 0),
+            _ => (1, 0),
+        };
+        for _ in 0..amount {
+            rope[0] = (rope[0].0 + dx, rope[0].1 + dy);
+            for i in 1..knots {
+                let (ahead, knot) = (rope[i - 1], rope[i]);
+                if (ahead.0 - knot.0).abs() > 1 || (ahead.1 - knot.1).abs() > 1 {
+                    rope[i] = (
+                        knot.0 + (ahead.0 - knot.0).signum(),
+                        knot.1 + (ahead.1 - knot.1).signum(),
+                    );
+                }
+            }
+            visited.insert(rope[knots - 1]);
+        }
+    }
+    visited.len()
+}
+
+#[cfg(test)]
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(2000))]
+    #[test]
+    fn test_solutions_match_reference(
+        moves in prop::collection::vec((prop::sample::select(vec!['U', 'D', 'L', 'R']), 1..=8usize), 0..30)
+    ) {
+        let text: String = moves
+            .iter()
+            .map(|(direction, amount)| format!("{} {}\n", direction, amount))
+            .collect();
+        let input = parse_input(&text).unwrap();
+        prop_assert_eq!(solution_1(&input), reference_tail_positions(&moves, 2));
+        prop_assert_eq!(solution_2(&input), reference_tail_positions(&moves, 10));
+    }
+}
+
+#[test]
+fn test_long_moves() {
+    let input = parse_input("R 600000\nU 600000\n").unwrap();
+    assert_eq!(solution_1(&input), 1199999);
+    assert_eq!(
+        parse_input("R 4\nL 2147483653\n").unwrap_err(),
+        ParseError::new(
+            2,
+            3,
+            "the head would move outside the range of `i32` coordinates"
+        )
+    );
+}
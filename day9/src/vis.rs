@@ -0,0 +1,110 @@
+use std::{collections::HashSet, rc::Rc};
+
+use aoc_common::{
+    position::Point,
+    vis::{Frame, Palette, Role, Visualize},
+    Params,
+};
+
+use crate::{steps, Day9, Move, Rope};
+
+const HALF_WIDTH: i32 = 30;
+const HALF_HEIGHT: i32 = 12;
+/// Every frame's rope is kept for the trail, so long inputs only animate their first steps.
+const MAX_FRAMES: usize = 100_000;
+
+/// The ten-knot rope after one step, framed around its head.
+pub struct RopeFrame {
+    rope: Rope,
+    /// Where the tail was after every step of the whole animation.
+    tails: Rc<Vec<Point>>,
+    step: usize,
+}
+
+impl Visualize for Day9 {
+    type Frame = RopeFrame;
+
+    fn frames<'a>(
+        input: &'a Self::Input,
+        _: &Params,
+    ) -> Box<dyn Iterator<Item = Self::Frame> + 'a> {
+        Box::new(rope_frames(input))
+    }
+}
+
+fn rope_frames(moves: &[Move]) -> impl Iterator<Item = RopeFrame> {
+    let ropes: Vec<Rope> = steps(moves, &vec![Point::default(); 10])
+        .take(MAX_FRAMES)
+        .collect();
+    let tails = Rc::new(ropes.iter().map(|rope| *rope.last().unwrap()).collect());
+    ropes
+        .into_iter()
+        .enumerate()
+        .map(move |(step, rope)| RopeFrame {
+            rope,
+            tails: Rc::clone(&tails),
+            step,
+        })
+}
+
+impl RopeFrame {
+    fn cell(&self, point: Point, trail: &HashSet<Point>) -> (String, Role) {
+        if let Some(knot) = self.rope.iter().position(|&knot| knot == point) {
+            return match knot {
+                0 => ("H".to_owned(), Role::Active),
+                _ => (knot.to_string(), Role::Highlight),
+            };
+        }
+        if point == Point::default() {
+            ("s".to_owned(), Role::Wall)
+        } else if trail.contains(&point) {
+            ("#".to_owned(), Role::Trail)
+        } else {
+            (".".to_owned(), Role::Empty)
+        }
+    }
+}
+
+impl Frame for RopeFrame {
+    fn render(&self, palette: &Palette) -> String {
+        let head = self.rope[0];
+        let in_view = |point: &&Point| {
+            (point.x - head.x).abs() <= HALF_WIDTH && (point.y - head.y).abs() <= HALF_HEIGHT
+        };
+        let trail: HashSet<Point> = self.tails[..=self.step]
+            .iter()
+            .filter(in_view)
+            .copied()
+            .collect();
+        // Rows run top to bottom, so start from the highest y.
+        (-HALF_HEIGHT..=HALF_HEIGHT)
+            .rev()
+            .map(|dy| {
+                (-HALF_WIDTH..=HALF_WIDTH)
+                    .map(|dx| {
+                        let (text, role) = self.cell(Point::new(head.x + dx, head.y + dy), &trail);
+                        palette.paint(&text, role)
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[test]
+fn test_rope_frames() {
+    let moves = crate::parse_input(include_str!("../example.txt")).unwrap();
+    let frames: Vec<RopeFrame> = rope_frames(&moves).collect();
+    let steps: usize = moves.iter().map(|m| m.amount).sum();
+    assert_eq!(frames.len(), steps + 1);
+    let first = frames[0].render(&Palette::mono());
+    let middle = first.lines().nth(HALF_HEIGHT as usize).unwrap();
+    assert_eq!(middle.chars().nth(HALF_WIDTH as usize), Some('H'));
+    let moves = crate::parse_input("R 20\nU 5\n").unwrap();
+    let last = rope_frames(&moves).last().unwrap();
+    let visited: HashSet<&Point> = last.tails.iter().collect();
+    assert_eq!(visited.len(), crate::solution_2(&moves));
+    let picture = last.render(&Palette::mono());
+    assert!(picture.contains('#') && picture.contains('s'));
+}
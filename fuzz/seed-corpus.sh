@@ -0,0 +1,11 @@
+#!/bin/sh
+# Copies each day's example and puzzle input into its fuzz target's corpus.
+set -eu
+cd "$(dirname "$0")"
+for dir in ../day*/; do
+    day=$(basename "$dir")
+    mkdir -p "corpus/$day"
+    for file in "$dir"*.txt; do
+        cp "$file" "corpus/$day/$(basename "$file")"
+    done
+done
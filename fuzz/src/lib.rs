@@ -0,0 +1,18 @@
+//! Fuzz targets, one per day, feeding arbitrary bytes to the day's parser and then to both
+//! parts of its solution. Run one with `cargo +nightly fuzz run day5` after seeding the
+//! corpus with `fuzz/seed-corpus.sh`.
+
+use aoc_common::{Answer, Params, Solution};
+
+/// Malformed input must come back as a parse error; a panic anywhere is a bug.
+pub fn check<S: Solution>(data: &[u8]) {
+    let Ok(input) = std::str::from_utf8(data) else {
+        return;
+    };
+    let Ok(parsed) = S::parse(input) else {
+        return;
+    };
+    let params = Params::defaults(S::PARAMS);
+    S::part_1(&parsed, &params).answer();
+    S::part_2(&parsed, &params).answer();
+}